@@ -5,6 +5,8 @@ use uuid::Uuid;
 use winit::window::CursorIcon;
 use torin::geometry::Size2D;
 
+use crate::PreferredTheme;
+
 /// Custom EventLoop messages
 #[derive(Debug)]
 pub enum EventMessage {
@@ -30,6 +32,8 @@ pub enum EventMessage {
     DragWindow,
     /// Set the window size
     SetWindowSize(Size2D),
+    /// The platform light/dark preference changed
+    PreferredThemeChanged(PreferredTheme),
     /// Close the whole app
     ExitApp,
 }
@@ -2,8 +2,10 @@ mod event_messages;
 mod layers;
 mod layout;
 mod paragraphs;
+mod preferred_theme;
 
 pub use event_messages::*;
 pub use layers::*;
 pub use layout::*;
 pub use paragraphs::*;
+pub use preferred_theme::*;
@@ -0,0 +1,25 @@
+use winit::window::Theme;
+
+/// Light or dark appearance preferred by the platform.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PreferredTheme {
+    /// Also used on platforms that don't report a preference.
+    #[default]
+    Light,
+    Dark,
+}
+
+impl PreferredTheme {
+    pub fn is_dark(&self) -> bool {
+        *self == Self::Dark
+    }
+}
+
+impl From<Theme> for PreferredTheme {
+    fn from(theme: Theme) -> Self {
+        match theme {
+            Theme::Light => Self::Light,
+            Theme::Dark => Self::Dark,
+        }
+    }
+}
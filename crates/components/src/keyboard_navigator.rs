@@ -12,7 +12,7 @@ pub fn KeyboardNavigator(children: Element) -> Element {
 
     let onkeydown = move |e: KeyboardEvent| {
         let allowed_to_navigate = navigation_mark.peek().allowed();
-        if e.key == Key::Tab && allowed_to_navigate {
+        if e.key == Key::Tab && allowed_to_navigate && !e.is_default_prevented() {
             if e.modifiers.contains(Modifiers::SHIFT) {
                 platform
                     .send(EventMessage::FocusPrevAccessibilityNode)
@@ -1,19 +1,103 @@
 use dioxus::prelude::*;
-use freya_hooks::{use_init_theme, Theme};
+use freya_hooks::{use_init_theme, use_preferred_theme, PreferredTheme, Theme, DARK_THEME};
 
 /// Properties for the [`ThemeProvider`] component.
 #[derive(Props, Clone, PartialEq)]
 pub struct ThemeProviderProps {
-    /// Theme to provide.
+    /// Theme to provide. When following the platform preference this is the light theme.
     pub theme: Option<Theme>,
+    /// Theme to provide when following the platform preference and it is dark. Defaults to [`DARK_THEME`].
+    pub dark_theme: Option<Theme>,
+    /// Switch between `theme` and `dark_theme` as the light/dark preference of the platform changes. Defaults to `false`.
+    #[props(default = false)]
+    pub follow_system: bool,
     /// Inner children to provide a Theme to.
     pub children: Element,
 }
 
 /// Provides a `Theme` for all its children.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     rsx!(
+///         ThemeProvider {
+///             follow_system: true,
+///             Button {
+///                 label { "Light or dark" }
+///             }
+///         }
+///     )
+/// }
+/// ```
 #[allow(non_snake_case)]
 pub fn ThemeProvider(props: ThemeProviderProps) -> Element {
-    use_init_theme(props.theme.unwrap_or_default());
+    let ThemeProviderProps {
+        theme,
+        dark_theme,
+        follow_system,
+        children,
+    } = props;
+    let preferred_theme = use_preferred_theme();
+    let select_theme = move |preferred_theme: PreferredTheme| match preferred_theme {
+        PreferredTheme::Dark if follow_system => dark_theme.clone().unwrap_or(DARK_THEME),
+        _ => theme.clone().unwrap_or_default(),
+    };
+    let mut current_theme = use_init_theme(select_theme(*preferred_theme.peek()));
 
-    rsx!({ props.children })
+    use_effect(move || {
+        let theme = select_theme(*preferred_theme.read());
+        if follow_system && *current_theme.peek() != theme {
+            current_theme.set(theme);
+        }
+    });
+
+    rsx!({ children })
+}
+
+#[cfg(test)]
+mod test {
+    use freya::prelude::*;
+    use freya_testing::prelude::*;
+
+    #[tokio::test]
+    pub async fn theme_provider_follows_system() {
+        fn theme_provider_app() -> Element {
+            rsx!(
+                ThemeProvider {
+                    follow_system: true,
+                    ThemeName { }
+                }
+            )
+        }
+
+        #[allow(non_snake_case)]
+        fn ThemeName() -> Element {
+            let theme = use_get_theme();
+
+            rsx!(
+                label {
+                    "{theme.name}"
+                }
+            )
+        }
+
+        let mut utils = launch_test(theme_provider_app);
+        let label = utils.root().get(0);
+        utils.wait_for_update().await;
+
+        assert_eq!(label.get(0).text(), Some(LIGHT_THEME.name.as_ref()));
+
+        utils.set_preferred_theme(PreferredTheme::Dark);
+        utils.wait_for_update().await;
+
+        assert_eq!(label.get(0).text(), Some(DARK_THEME.name.as_ref()));
+
+        utils.set_preferred_theme(PreferredTheme::Light);
+        utils.wait_for_update().await;
+
+        assert_eq!(label.get(0).text(), Some(LIGHT_THEME.name.as_ref()));
+    }
 }
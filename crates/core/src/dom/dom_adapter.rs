@@ -50,11 +50,19 @@ impl DOMAdapter<NodeId> for DioxusDOMAdapter<'_> {
             margin: layout.margin,
             main_alignment: layout.main_alignment,
             cross_alignment: layout.cross_alignment,
+            align_self: layout.align_self,
+            aspect_ratio: layout.aspect_ratio,
             offset_x: layout.offset_x,
             offset_y: layout.offset_y,
             has_layout_references: layout.node_ref.is_some(),
             position: layout.position,
             content: layout.content,
+            wrap_content: layout.wrap_content,
+            spacing: layout.spacing,
+            grid_columns: layout.grid_columns,
+            grid_rows: layout.grid_rows,
+            grid_column: layout.grid_column,
+            grid_row: layout.grid_row,
             contains_text,
         })
     }
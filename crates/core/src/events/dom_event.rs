@@ -3,7 +3,8 @@ use std::{any::Any, rc::Rc};
 use freya_elements::{
     elements::PlatformEventData,
     events::{
-        pointer::PointerType, FileData, KeyboardData, MouseData, PointerData, TouchData, WheelData,
+        pointer::PointerType, EventFlags, FileData, FocusData, KeyboardData, MouseData,
+        PointerData, TouchData, WheelData,
     },
 };
 use freya_native_core::NodeId;
@@ -72,6 +73,7 @@ impl DomEvent {
                         screen_coordinates,
                         (element_x, element_y).into(),
                         button,
+                        0,
                     ))
                 };
 
@@ -166,9 +168,34 @@ pub enum DomEventData {
     Touch(TouchData),
     Pointer(PointerData),
     File(FileData),
+    Focus(FocusData),
 }
 
 impl DomEventData {
+    /// Get the flags used to cancel the default behaviour of this event or stop it from reaching other listeners, if it has them.
+    pub fn flags_mut(&mut self) -> Option<&mut EventFlags> {
+        match self {
+            DomEventData::Mouse(m) => Some(&mut m.flags),
+            DomEventData::Keyboard(k) => Some(&mut k.flags),
+            DomEventData::Wheel(w) => Some(&mut w.flags),
+            DomEventData::Touch(t) => Some(&mut t.flags),
+            DomEventData::Pointer(p) => Some(&mut p.flags),
+            DomEventData::File(_) | DomEventData::Focus(_) => None,
+        }
+    }
+
+    /// Check if a listener stopped this event from reaching the rest of listeners.
+    pub fn is_propagation_stopped(&self) -> bool {
+        match self {
+            DomEventData::Mouse(m) => m.is_capture_propagation_stopped(),
+            DomEventData::Keyboard(k) => k.is_capture_propagation_stopped(),
+            DomEventData::Wheel(w) => w.is_capture_propagation_stopped(),
+            DomEventData::Touch(t) => t.is_capture_propagation_stopped(),
+            DomEventData::Pointer(p) => p.is_capture_propagation_stopped(),
+            DomEventData::File(_) | DomEventData::Focus(_) => false,
+        }
+    }
+
     pub fn any(self) -> Rc<dyn Any> {
         match self {
             DomEventData::Mouse(m) => Rc::new(PlatformEventData::new(Box::new(m))),
@@ -177,6 +204,7 @@ impl DomEventData {
             DomEventData::Touch(t) => Rc::new(PlatformEventData::new(Box::new(t))),
             DomEventData::Pointer(p) => Rc::new(PlatformEventData::new(Box::new(p))),
             DomEventData::File(fd) => Rc::new(PlatformEventData::new(Box::new(fd))),
+            DomEventData::Focus(f) => Rc::new(PlatformEventData::new(Box::new(f))),
         }
     }
 }
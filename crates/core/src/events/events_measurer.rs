@@ -1,9 +1,11 @@
 use freya_native_core::NodeId;
 use freya_native_core::{real_dom::NodeImmutable, tree::TreeRef};
 
+use freya_elements::events::{EventFlags, FocusData};
 use freya_engine::prelude::*;
-use freya_node_state::{Fill, Style, ViewportState};
+use freya_node_state::{AccessibilityNodeState, Fill, Style, ViewportState};
 use itertools::sorted;
+use rustc_hash::FxHashMap;
 
 pub use crate::events::{DomEvent, NodesState, PlatformEvent};
 
@@ -17,12 +19,19 @@ pub fn process_events(
     nodes_state: &mut NodesState,
     scale_factor: f64,
 ) {
+    // 0. Count the consecutive clicks, e.g. click -> doubleclick
+    let click_count = nodes_state.process_clicks(events, scale_factor);
+
     // 1. Get global events created from the incoming events
     let global_events = measure_global_events(events);
 
     // 2. Get potential events that could be emitted based on the elements layout and viewports
     let potential_events = measure_potential_event_listeners(events, dom);
 
+    // 2.1 Get the capture events, dispatched from the root down to the target before the rest
+    let mut to_emit_capture_events =
+        measure_capture_events(&potential_events, dom, nodes_state, scale_factor);
+
     // 3. Get what events can be actually emitted based on what elements are listening
     let dom_events = measure_dom_events(potential_events, dom, scale_factor);
 
@@ -34,30 +43,192 @@ pub fn process_events(
     let to_emit_dom_collateral_events =
         measure_dom_events(potential_collateral_events, dom, scale_factor);
 
-    let colateral_global_events = measure_colateral_global_events(&to_emit_dom_collateral_events);
+    let mut colateral_global_events =
+        measure_colateral_global_events(&to_emit_dom_collateral_events);
 
-    // 6. Join both the dom and colateral dom events and sort them
+    // 6. Join both the dom and colateral dom events and sort them.
+    // The sort is stable so events with the same name keep their order, e.g. the target before the keyboard navigator
     to_emit_dom_events.extend(to_emit_dom_collateral_events);
-    to_emit_dom_events.sort_unstable();
+    to_emit_dom_events.sort();
+
+    set_click_count(&mut to_emit_capture_events, click_count);
+    set_click_count(&mut to_emit_dom_events, click_count);
+    set_click_count(&mut colateral_global_events, click_count);
 
-    // 7. Emit the DOM events
-    for event in to_emit_dom_events {
+    // 7. Send the Pointer events to the Node that captured the pointer
+    capture_pointer_events(
+        &mut to_emit_dom_events,
+        events,
+        nodes_state,
+        dom,
+        scale_factor,
+    );
+
+    // 8. Emit the capture events and then the DOM events
+    to_emit_capture_events.extend(to_emit_dom_events);
+    share_event_flags(&mut to_emit_capture_events);
+
+    for event in to_emit_capture_events {
         event_emitter.send(event).unwrap();
     }
 
-    // 8. Emit the global events
+    // 9. Emit the global events
     emit_global_events_listeners(
         global_events,
         colateral_global_events,
         dom,
         event_emitter,
         scale_factor,
+        click_count,
     );
 
-    // 9. Clear the events queue
+    // 10. Clear the events queue
     events.clear();
 }
 
+/// Set the clicks count of the mouse events that count them
+fn set_click_count(events: &mut [DomEvent], click_count: u32) {
+    for event in events {
+        if let DomEventData::Mouse(data) = &mut event.data {
+            if event.name.is_click_counted() {
+                data.click_count = click_count;
+            }
+        }
+    }
+}
+
+/// Make the events with the same name, and their capture variants, share the same [EventFlags],
+/// so any of their listeners can cancel the default behaviour for the rest, e.g. the keyboard navigation,
+/// or stop the event from reaching them.
+fn share_event_flags(events: &mut [DomEvent]) {
+    let mut flags = FxHashMap::<EventName, EventFlags>::default();
+    for event in events {
+        let flags = flags.entry(event.name.get_captured_event()).or_default();
+        if let Some(event_flags) = event.data.flags_mut() {
+            *event_flags = flags.clone();
+        }
+    }
+}
+
+/// Measure the capture variants of the events, e.g. `click` -> `clickcapture`.
+/// These are emitted to every listening Node from the root down to the target of the event,
+/// so parents can intercept events before their children.
+fn measure_capture_events(
+    potential_events: &PotentialEvents,
+    fdom: &FreyaDOM,
+    nodes_state: &NodesState,
+    scale_factor: f64,
+) -> Vec<DomEvent> {
+    let mut capture_events = Vec::new();
+    let rdom = fdom.rdom();
+    let layout = fdom.layout();
+
+    for (event_name, event_nodes) in potential_events {
+        for collateral_event in event_name.get_collateral_events() {
+            let Some(capture_event) = collateral_event.get_capture_event() else {
+                continue;
+            };
+
+            let path = if collateral_event.does_go_through_solid() {
+                // Events like the keyboard ones target every Node
+                event_nodes.clone()
+            } else {
+                let Some(top_event) = event_nodes.last() else {
+                    continue;
+                };
+
+                // The target is the top-most Node under the cursor, or the one that captured the pointer
+                let target_id = nodes_state
+                    .pointer_capture()
+                    .filter(|node_id| collateral_event.can_be_captured() && rdom.contains(*node_id))
+                    .unwrap_or(top_event.node_id);
+
+                let mut path = Vec::new();
+                let mut node_id = Some(target_id);
+                while let Some(id) = node_id {
+                    path.push(PotentialEvent {
+                        node_id: id,
+                        layer: None,
+                        event: top_event.event.clone(),
+                    });
+                    node_id = rdom.get(id).and_then(|node| node.parent_id());
+                }
+                path.reverse();
+                path
+            };
+
+            for mut potential_event in path {
+                if !rdom.is_node_listening(&potential_event.node_id, &capture_event) {
+                    continue;
+                }
+                potential_event.event.set_name(capture_event);
+                let node_area = layout
+                    .get(potential_event.node_id)
+                    .map(|layout_node| layout_node.visible_area());
+                capture_events.push(DomEvent::new(potential_event, node_area, scale_factor));
+            }
+        }
+    }
+
+    capture_events
+}
+
+/// Send the Pointer events to the Node that captured the pointer instead of the ones under it,
+/// and let the listeners of Pointer events capture the pointer
+fn capture_pointer_events(
+    events_to_emit: &mut Vec<DomEvent>,
+    events: &EventsQueue,
+    nodes_state: &NodesState,
+    fdom: &FreyaDOM,
+    scale_factor: f64,
+) {
+    let rdom = fdom.rdom();
+
+    if let Some(node_id) = nodes_state.pointer_capture() {
+        if rdom.contains(node_id) {
+            events_to_emit.retain(|event| !event.name.can_be_captured());
+
+            let node_area = fdom
+                .layout()
+                .get(node_id)
+                .map(|layout_node| layout_node.visible_area());
+
+            for event in events {
+                for event_name in event.get_name().get_collateral_events() {
+                    if event_name.can_be_captured() && rdom.is_node_listening(&node_id, &event_name)
+                    {
+                        let mut event = event.clone();
+                        event.set_name(event_name);
+                        events_to_emit.push(DomEvent::new(
+                            PotentialEvent {
+                                node_id,
+                                layer: None,
+                                event,
+                            },
+                            node_area,
+                            scale_factor,
+                        ));
+                    }
+                }
+            }
+        }
+
+        let released = !rdom.contains(node_id)
+            || events
+                .iter()
+                .any(|event| event.get_name().releases_pointer_capture());
+        if released {
+            nodes_state.release_pointer_capture();
+        }
+    }
+
+    for event in events_to_emit {
+        if let DomEventData::Pointer(data) = &mut event.data {
+            data.pointer_capture = nodes_state.pointer_capture_for(event.node_id);
+        }
+    }
+}
+
 /// Measure colateral global events
 pub fn measure_colateral_global_events(events: &[DomEvent]) -> Vec<DomEvent> {
     let mut global_events = Vec::default();
@@ -262,13 +433,14 @@ fn emit_global_events_listeners(
     fdom: &FreyaDOM,
     event_emitter: &EventEmitter,
     scale_factor: f64,
+    click_count: u32,
 ) {
     for global_event in global_events {
         let event_name = global_event.get_name();
         let listeners = fdom.rdom().get_listeners(&event_name);
 
         for listener in listeners {
-            let event = DomEvent::new(
+            let mut event = DomEvent::new(
                 PotentialEvent {
                     node_id: listener.id(),
                     layer: None,
@@ -277,6 +449,7 @@ fn emit_global_events_listeners(
                 None,
                 scale_factor,
             );
+            set_click_count(std::slice::from_mut(&mut event), click_count);
             event_emitter.send(event).unwrap();
         }
     }
@@ -284,3 +457,80 @@ fn emit_global_events_listeners(
         event_emitter.send(colateral_global_event).unwrap();
     }
 }
+
+/// Emit the focus events caused by moving the focus from one accessibility Node to another:
+/// - `blur` and `focusout` on the Node that lost the focus
+/// - `focus` and `focusin` on the Node that got focused
+pub fn emit_focus_events(
+    fdom: &FreyaDOM,
+    event_emitter: &EventEmitter,
+    blurred_id: AccessibilityId,
+    focused_id: AccessibilityId,
+) {
+    if blurred_id == focused_id {
+        return;
+    }
+
+    let rdom = fdom.rdom();
+
+    let focus_changes = [
+        (blurred_id, EventName::Blur, EventName::FocusOut),
+        (focused_id, EventName::Focus, EventName::FocusIn),
+    ];
+
+    for (accessibility_id, event_name, bubbling_event_name) in focus_changes {
+        if accessibility_id == ACCESSIBILITY_ROOT_ID {
+            continue;
+        }
+
+        let Some(node_id) = find_accessible_node(rdom, accessibility_id) else {
+            continue;
+        };
+
+        if rdom.is_node_listening(&node_id, &event_name) {
+            event_emitter
+                .send(focus_event(node_id, event_name))
+                .unwrap();
+        }
+
+        // Bubbling events are emitted to the closest listener, which will propagate it to its ancestors
+        let mut target_id = Some(node_id);
+        while let Some(node_id) = target_id {
+            if rdom.is_node_listening(&node_id, &bubbling_event_name) {
+                event_emitter
+                    .send(focus_event(node_id, bubbling_event_name))
+                    .unwrap();
+                break;
+            }
+            target_id = rdom.get(node_id).and_then(|node| node.parent_id());
+        }
+    }
+}
+
+fn focus_event(node_id: NodeId, name: EventName) -> DomEvent {
+    DomEvent {
+        name,
+        node_id,
+        data: DomEventData::Focus(FocusData),
+        bubbles: name.does_bubble(),
+        layer: None,
+    }
+}
+
+/// Find the Node with the given accessibility ID
+fn find_accessible_node(rdom: &DioxusDOM, accessibility_id: AccessibilityId) -> Option<NodeId> {
+    let mut found = None;
+    rdom.traverse_depth_first_advanced(|node| {
+        if found.is_some() {
+            return false;
+        }
+        let node_accessibility = node.get::<AccessibilityNodeState>();
+        if node_accessibility.and_then(|node_accessibility| node_accessibility.accessibility_id)
+            == Some(accessibility_id)
+        {
+            found = Some(node.id());
+        }
+        found.is_none()
+    });
+    found
+}
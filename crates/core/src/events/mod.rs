@@ -4,6 +4,7 @@ pub mod events_measurer;
 pub mod nodes_state;
 pub mod platform_event;
 pub mod potential_event;
+pub mod recording;
 
 pub use dom_event::*;
 pub use event_name::*;
@@ -11,3 +12,4 @@ pub use events_measurer::*;
 pub use nodes_state::*;
 pub use platform_event::*;
 pub use potential_event::*;
+pub use recording::*;
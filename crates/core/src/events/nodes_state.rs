@@ -1,25 +1,164 @@
 #![allow(clippy::type_complexity)]
 
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use freya_elements::events::PointerCapture;
 use freya_native_core::NodeId;
 use rustc_hash::FxHashMap;
+use tokio::time::Instant;
+use torin::prelude::CursorPoint;
+use winit::event::MouseButton;
 
 use crate::{
     events::{DomEvent, PlatformEvent},
-    prelude::{EventName, PotentialEvent, PotentialEvents},
+    prelude::{EventName, EventsQueue, PotentialEvent, PotentialEvents},
 };
 
+/// Default maximum time between two presses of a mouse button to be counted as consecutive clicks.
+/// Same as the default of most platforms, but it's not read from the settings of the platform.
+pub const DOUBLE_CLICK_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Default maximum logical distance the cursor can move between two presses of a mouse button to be counted as consecutive clicks.
+/// It's not read from the settings of the platform either.
+pub const DOUBLE_CLICK_DISTANCE: f64 = 4.0;
+
 #[derive(Clone)]
 struct NodeMetadata {
     layer: Option<i16>,
 }
 
+#[derive(Clone)]
+struct LastPress {
+    time: Instant,
+    cursor: CursorPoint,
+    button: MouseButton,
+    count: u32,
+}
+
 /// [`NodesState`] stores the nodes states given incoming events.
-#[derive(Default)]
 pub struct NodesState {
     hovered_nodes: FxHashMap<NodeId, NodeMetadata>,
+    last_press: Option<LastPress>,
+    pointer_capture: Arc<Mutex<Option<NodeId>>>,
+    double_click_interval: Duration,
+    double_click_distance: f64,
+}
+
+impl Default for NodesState {
+    fn default() -> Self {
+        Self {
+            hovered_nodes: FxHashMap::default(),
+            last_press: None,
+            pointer_capture: Arc::default(),
+            double_click_interval: DOUBLE_CLICK_INTERVAL,
+            double_click_distance: DOUBLE_CLICK_DISTANCE,
+        }
+    }
 }
 
 impl NodesState {
+    /// Change the maximum time between two presses of a mouse button to be counted as consecutive clicks.
+    pub fn set_double_click_interval(&mut self, double_click_interval: Duration) {
+        self.double_click_interval = double_click_interval;
+    }
+
+    /// Change the maximum logical distance the cursor can move between two presses of a mouse button to be counted as consecutive clicks.
+    pub fn set_double_click_distance(&mut self, double_click_distance: f64) {
+        self.double_click_distance = double_click_distance;
+    }
+
+    /// Make the given Node receive the Pointer events wherever the pointer goes, until it's released.
+    pub fn set_pointer_capture(&self, node_id: NodeId) {
+        *self.pointer_capture.lock().unwrap() = Some(node_id);
+    }
+
+    /// Release the captured pointer, if any.
+    pub fn release_pointer_capture(&self) {
+        *self.pointer_capture.lock().unwrap() = None;
+    }
+
+    /// Get the Node that captured the pointer, if any.
+    pub fn pointer_capture(&self) -> Option<NodeId> {
+        *self.pointer_capture.lock().unwrap()
+    }
+
+    /// Create a [PointerCapture] to let the given Node capture or release the pointer from its event listeners.
+    pub fn pointer_capture_for(&self, node_id: NodeId) -> PointerCapture {
+        let pointer_capture = self.pointer_capture.clone();
+        PointerCapture::new(move |capture| {
+            let mut pointer_capture = pointer_capture.lock().unwrap();
+            if capture {
+                *pointer_capture = Some(node_id);
+            } else if *pointer_capture == Some(node_id) {
+                *pointer_capture = None;
+            }
+        })
+    }
+
+    /// Count the consecutive clicks of the pressed mouse buttons,
+    /// and add a `doubleclick` event after the second click of the left button.
+    ///
+    /// Returns the clicks count of the last pressed button.
+    pub fn process_clicks(&mut self, events: &mut EventsQueue, scale_factor: f64) -> u32 {
+        let mut double_clicks = Vec::new();
+
+        for event in events.iter() {
+            let PlatformEvent::Mouse {
+                name,
+                cursor,
+                button: Some(button),
+            } = event
+            else {
+                continue;
+            };
+
+            match name {
+                EventName::MouseDown => {
+                    let now = Instant::now();
+                    let count = match &self.last_press {
+                        Some(last_press)
+                            if last_press.button == *button
+                                && now.duration_since(last_press.time)
+                                    <= self.double_click_interval
+                                && last_press.cursor.distance_to(*cursor)
+                                    <= self.double_click_distance * scale_factor =>
+                        {
+                            last_press.count + 1
+                        }
+                        _ => 1,
+                    };
+                    self.last_press = Some(LastPress {
+                        time: now,
+                        cursor: *cursor,
+                        button: *button,
+                        count,
+                    });
+                }
+                EventName::Click => {
+                    let is_double_click = self.last_press.as_ref().is_some_and(|last_press| {
+                        last_press.button == MouseButton::Left && last_press.count == 2
+                    });
+                    if is_double_click {
+                        double_clicks.push(PlatformEvent::Mouse {
+                            name: EventName::DoubleClick,
+                            cursor: *cursor,
+                            button: Some(*button),
+                        });
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        events.extend(double_clicks);
+
+        self.last_press
+            .as_ref()
+            .map(|last_press| last_press.count)
+            .unwrap_or_default()
+    }
+
     /// Update the node states given the new events
     pub fn process_events(
         &mut self,
@@ -0,0 +1,356 @@
+use std::fmt::{self, Display};
+use std::path::PathBuf;
+use std::str::{FromStr, SplitWhitespace};
+use std::time::Duration;
+
+use freya_elements::events::keyboard::{Code, Key, Modifiers};
+use torin::prelude::*;
+use winit::event::{Force, MouseButton, TouchPhase};
+
+use crate::prelude::{EventName, PlatformEvent};
+
+/// Event captured while recording an app.
+#[derive(Clone, Debug)]
+pub enum RecordedEvent {
+    /// An event emitted by the platform.
+    Platform(PlatformEvent),
+    /// The window was resized to the given physical size.
+    Resize(Size2D),
+}
+
+/// A [RecordedEvent] and when it happened since the recording started.
+#[derive(Clone, Debug)]
+pub struct RecordedEntry {
+    pub time: Duration,
+    pub event: RecordedEvent,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseEventsRecordingError {
+    /// Line of the recording that could not be parsed, starting from 1
+    pub line: usize,
+}
+
+/// Events recorded from an app, so they can be replayed later.
+///
+/// Its [Display] implementation writes a stable text format with one entry per line, which can be parsed back with [EventsRecording::parse].
+#[derive(Clone, Debug)]
+pub struct EventsRecording {
+    /// Physical size of the window when the recording started.
+    pub window_size: Size2D,
+    /// Scale factor of the window.
+    pub scale_factor: f64,
+    pub entries: Vec<RecordedEntry>,
+}
+
+impl EventsRecording {
+    pub fn new(window_size: Size2D, scale_factor: f64) -> Self {
+        Self {
+            window_size,
+            scale_factor,
+            entries: Vec::new(),
+        }
+    }
+
+    /// Add an event that happened at the given time since the recording started.
+    pub fn push(&mut self, time: Duration, event: RecordedEvent) {
+        self.entries.push(RecordedEntry { time, event });
+    }
+
+    /// Parse a recording previously created with its [Display] implementation.
+    pub fn parse(value: &str) -> Result<Self, ParseEventsRecordingError> {
+        let mut lines = value
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty());
+
+        let mut header = |key: &str| {
+            let (i, line) = lines.next().ok_or(ParseEventsRecordingError { line: 1 })?;
+            line.strip_prefix(key)
+                .map(|value| value.split_whitespace())
+                .ok_or(ParseEventsRecordingError { line: i + 1 })
+                .map(|values| (i, values))
+        };
+
+        let (i, mut values) = header("window_size ")?;
+        let window_size =
+            parse_size(&mut values).ok_or(ParseEventsRecordingError { line: i + 1 })?;
+
+        let (i, mut values) = header("scale_factor ")?;
+        let scale_factor =
+            parse_next(&mut values).ok_or(ParseEventsRecordingError { line: i + 1 })?;
+
+        let entries = lines
+            .map(|(i, line)| parse_entry(line).ok_or(ParseEventsRecordingError { line: i + 1 }))
+            .collect::<Result<Vec<RecordedEntry>, ParseEventsRecordingError>>()?;
+
+        Ok(Self {
+            window_size,
+            scale_factor,
+            entries,
+        })
+    }
+}
+
+impl Display for EventsRecording {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "window_size {} {}",
+            self.window_size.width, self.window_size.height
+        )?;
+        writeln!(f, "scale_factor {}", self.scale_factor)?;
+        for entry in &self.entries {
+            writeln!(f, "{entry}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for RecordedEntry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ", self.time.as_millis())?;
+        match &self.event {
+            RecordedEvent::Resize(size) => write!(f, "resize {} {}", size.width, size.height),
+            RecordedEvent::Platform(event) => {
+                let name: &str = event.get_name().into();
+                match event {
+                    PlatformEvent::Mouse { cursor, button, .. } => write!(
+                        f,
+                        "mouse {name} {} {} {}",
+                        cursor.x,
+                        cursor.y,
+                        format_button(button)
+                    ),
+                    PlatformEvent::Wheel { scroll, cursor, .. } => write!(
+                        f,
+                        "wheel {name} {} {} {} {}",
+                        scroll.x, scroll.y, cursor.x, cursor.y
+                    ),
+                    PlatformEvent::Keyboard {
+                        key,
+                        code,
+                        modifiers,
+                        ..
+                    } => write!(
+                        f,
+                        "keyboard {name} {} {code} {}",
+                        format_key(key),
+                        modifiers.bits()
+                    ),
+                    PlatformEvent::Touch {
+                        location,
+                        finger_id,
+                        phase,
+                        force,
+                        ..
+                    } => write!(
+                        f,
+                        "touch {name} {} {} {finger_id} {} {}",
+                        location.x,
+                        location.y,
+                        format_phase(phase),
+                        format_force(force)
+                    ),
+                    PlatformEvent::File {
+                        cursor, file_path, ..
+                    } => write!(
+                        f,
+                        "file {name} {} {} {}",
+                        cursor.x,
+                        cursor.y,
+                        file_path
+                            .as_ref()
+                            .map(|path| escape(&path.to_string_lossy()))
+                            .unwrap_or_else(|| "none".to_string())
+                    ),
+                }
+            }
+        }
+    }
+}
+
+/// Escape the characters that would break the whitespace separated format.
+fn escape(value: &str) -> String {
+    value
+        .replace('%', "%25")
+        .replace(' ', "%20")
+        .replace('\t', "%09")
+        .replace('\n', "%0A")
+        .replace('\r', "%0D")
+}
+
+fn unescape(value: &str) -> String {
+    value
+        .replace("%20", " ")
+        .replace("%09", "\t")
+        .replace("%0A", "\n")
+        .replace("%0D", "\r")
+        .replace("%25", "%")
+}
+
+fn format_button(button: &Option<MouseButton>) -> String {
+    match button {
+        None => "none".to_string(),
+        Some(MouseButton::Left) => "left".to_string(),
+        Some(MouseButton::Right) => "right".to_string(),
+        Some(MouseButton::Middle) => "middle".to_string(),
+        Some(MouseButton::Back) => "back".to_string(),
+        Some(MouseButton::Forward) => "forward".to_string(),
+        Some(MouseButton::Other(button)) => format!("other:{button}"),
+    }
+}
+
+fn parse_button(value: &str) -> Option<Option<MouseButton>> {
+    let button = match value {
+        "none" => return Some(None),
+        "left" => MouseButton::Left,
+        "right" => MouseButton::Right,
+        "middle" => MouseButton::Middle,
+        "back" => MouseButton::Back,
+        "forward" => MouseButton::Forward,
+        value => MouseButton::Other(value.strip_prefix("other:")?.parse().ok()?),
+    };
+    Some(Some(button))
+}
+
+fn format_key(key: &Key) -> String {
+    if let Key::Character(character) = key {
+        format!("char:{}", escape(character))
+    } else {
+        key.to_string()
+    }
+}
+
+fn parse_key(value: &str) -> Option<Key> {
+    if let Some(character) = value.strip_prefix("char:") {
+        Some(Key::Character(unescape(character)))
+    } else {
+        Key::from_str(value).ok()
+    }
+}
+
+fn format_phase(phase: &TouchPhase) -> &'static str {
+    match phase {
+        TouchPhase::Started => "started",
+        TouchPhase::Moved => "moved",
+        TouchPhase::Ended => "ended",
+        TouchPhase::Cancelled => "cancelled",
+    }
+}
+
+fn parse_phase(value: &str) -> Option<TouchPhase> {
+    match value {
+        "started" => Some(TouchPhase::Started),
+        "moved" => Some(TouchPhase::Moved),
+        "ended" => Some(TouchPhase::Ended),
+        "cancelled" => Some(TouchPhase::Cancelled),
+        _ => None,
+    }
+}
+
+fn format_force(force: &Option<Force>) -> String {
+    match force {
+        None => "none".to_string(),
+        Some(Force::Normalized(force)) => format!("normalized:{force}"),
+        Some(Force::Calibrated {
+            force,
+            max_possible_force,
+            altitude_angle,
+        }) => format!(
+            "calibrated:{force}:{max_possible_force}:{}",
+            altitude_angle
+                .map(|angle| angle.to_string())
+                .unwrap_or_else(|| "none".to_string())
+        ),
+    }
+}
+
+fn parse_force(value: &str) -> Option<Option<Force>> {
+    if value == "none" {
+        return Some(None);
+    }
+
+    if let Some(force) = value.strip_prefix("normalized:") {
+        return Some(Some(Force::Normalized(force.parse().ok()?)));
+    }
+
+    let mut values = value.strip_prefix("calibrated:")?.split(':');
+    let force = values.next()?.parse().ok()?;
+    let max_possible_force = values.next()?.parse().ok()?;
+    let altitude_angle = match values.next()? {
+        "none" => None,
+        angle => Some(angle.parse().ok()?),
+    };
+
+    Some(Some(Force::Calibrated {
+        force,
+        max_possible_force,
+        altitude_angle,
+    }))
+}
+
+fn parse_next<T: FromStr>(values: &mut SplitWhitespace) -> Option<T> {
+    values.next()?.parse().ok()
+}
+
+fn parse_point(values: &mut SplitWhitespace) -> Option<CursorPoint> {
+    Some(CursorPoint::new(parse_next(values)?, parse_next(values)?))
+}
+
+fn parse_size(values: &mut SplitWhitespace) -> Option<Size2D> {
+    Some(Size2D::new(parse_next(values)?, parse_next(values)?))
+}
+
+fn parse_entry(line: &str) -> Option<RecordedEntry> {
+    let mut values = line.split_whitespace();
+    let time = Duration::from_millis(parse_next(&mut values)?);
+    let kind = values.next()?;
+
+    let event = if kind == "resize" {
+        RecordedEvent::Resize(parse_size(&mut values)?)
+    } else {
+        let name = EventName::from_str(values.next()?).ok()?;
+        let event = match kind {
+            "mouse" => PlatformEvent::Mouse {
+                name,
+                cursor: parse_point(&mut values)?,
+                button: parse_button(values.next()?)?,
+            },
+            "wheel" => PlatformEvent::Wheel {
+                name,
+                scroll: parse_point(&mut values)?,
+                cursor: parse_point(&mut values)?,
+            },
+            "keyboard" => PlatformEvent::Keyboard {
+                name,
+                key: parse_key(values.next()?)?,
+                code: Code::from_str(values.next()?).ok()?,
+                modifiers: Modifiers::from_bits_truncate(parse_next(&mut values)?),
+            },
+            "touch" => PlatformEvent::Touch {
+                name,
+                location: parse_point(&mut values)?,
+                finger_id: parse_next(&mut values)?,
+                phase: parse_phase(values.next()?)?,
+                force: parse_force(values.next()?)?,
+            },
+            "file" => PlatformEvent::File {
+                name,
+                cursor: parse_point(&mut values)?,
+                file_path: match values.next()? {
+                    "none" => None,
+                    path => Some(PathBuf::from(unescape(path))),
+                },
+            },
+            _ => return None,
+        };
+        RecordedEvent::Platform(event)
+    };
+
+    if values.next().is_some() {
+        return None;
+    }
+
+    Some(RecordedEntry { time, event })
+}
@@ -25,4 +25,6 @@ pub mod prelude {
     pub use crate::types::FocusReceiver;
     pub use crate::types::FocusSender;
     pub use crate::types::PotentialEvents;
+    pub use crate::types::PreferredThemeReceiver;
+    pub use crate::types::PreferredThemeSender;
 }
@@ -111,7 +111,9 @@ impl<'a> Iterator for NodeStateIterator<'a> {
                 let background = &self.state.style.background;
                 let fill = match *background {
                     Fill::Color(_) => AttributeType::Color(background.clone()),
-                    Fill::LinearGradient(_) => AttributeType::LinearGradient(background.clone()),
+                    Fill::LinearGradient(_) | Fill::RadialGradient(_) | Fill::ConicGradient(_) => {
+                        AttributeType::Gradient(background.clone())
+                    }
                 };
                 Some(("background", fill))
             }
@@ -180,7 +182,7 @@ impl<'a> Iterator for NodeStateIterator<'a> {
 
 pub enum AttributeType<'a> {
     Color(Fill),
-    LinearGradient(Fill),
+    Gradient(Fill),
     Size(&'a Size),
     Measure(f32),
     Measures(Gaps),
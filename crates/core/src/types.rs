@@ -3,6 +3,7 @@ use crate::{
     prelude::{EventName, PotentialEvent},
 };
 pub use accesskit::NodeId as AccessibilityId;
+use freya_common::PreferredTheme;
 use rustc_hash::FxHashMap;
 use smallvec::SmallVec;
 use tokio::sync::mpsc::{UnboundedReceiver, UnboundedSender};
@@ -14,6 +15,12 @@ pub type FocusSender = watch::Sender<AccessibilityId>;
 /// Receive updates by the platform of the focused elements
 pub type FocusReceiver = watch::Receiver<AccessibilityId>;
 
+/// Send updates of the platform light/dark preference.
+pub type PreferredThemeSender = watch::Sender<PreferredTheme>;
+
+/// Receive updates of the platform light/dark preference.
+pub type PreferredThemeReceiver = watch::Receiver<PreferredTheme>;
+
 /// Emit events to the VirtualDOM
 pub type EventEmitter = UnboundedSender<DomEvent>;
 
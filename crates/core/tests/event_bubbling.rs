@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use freya::prelude::*;
 use freya_testing::prelude::*;
 
@@ -47,6 +49,54 @@ pub async fn captured_event() {
     assert_eq!(label.get(0).text(), Some("2"));
 }
 
+#[tokio::test]
+pub async fn nested_tiles_stop_propagation() {
+    fn app() -> Element {
+        let mut outer_clicks = use_signal(|| 0);
+        let mut inner_clicks = use_signal(|| 0);
+
+        rsx!(
+            rect {
+                height: "200",
+                width: "200",
+                onclick: move |_: MouseEvent| {
+                    outer_clicks += 1;
+                },
+                rect {
+                    height: "100",
+                    width: "100",
+                    onclick: move |e: MouseEvent| {
+                        inner_clicks += 1;
+                        e.stop_propagation();
+                    }
+                }
+            }
+            label {
+                "{outer_clicks} {inner_clicks}"
+            }
+        )
+    }
+
+    let mut utils = launch_test(app);
+    utils.wait_for_update().await;
+
+    let outer = utils.root().get(0);
+    let inner = outer.get(0);
+    let label = utils.root().get(1);
+
+    // The inner tile stops the click from reaching the outer tile
+    utils.click(&inner).await;
+    assert_eq!(label.get(0).text(), Some("0 1"));
+
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::Click,
+        cursor: CursorPoint::new(150.0, 150.0),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+    assert_eq!(label.get(0).text(), Some("1 1"));
+}
+
 #[tokio::test]
 pub async fn not_captured_event() {
     fn app() -> Element {
@@ -184,3 +234,346 @@ pub async fn event_cant_pass_through_wall() {
 
     assert_eq!(label.get(0).text(), Some(""));
 }
+
+#[tokio::test]
+pub async fn double_click_bubbles() {
+    fn app() -> Element {
+        let mut double_clicks = use_signal(Vec::<&str>::new);
+        let mut click_counts = use_signal(Vec::<u32>::new);
+        let double_clicks_text = double_clicks.read().join(" ");
+        let click_counts_text = format!("{:?}", click_counts.read());
+
+        rsx!(
+            rect {
+                height: "100%",
+                width: "100%",
+                ondoubleclick: move |_: MouseEvent| {
+                    double_clicks.write().push("parent");
+                },
+                rect {
+                    height: "100",
+                    width: "100",
+                    background: "red",
+                    onclick: move |e: MouseEvent| {
+                        click_counts.write().push(e.get_click_count());
+                    },
+                    ondoubleclick: move |_: MouseEvent| {
+                        double_clicks.write().push("child");
+                    }
+                }
+                label {
+                    "{double_clicks_text}"
+                }
+                label {
+                    "{click_counts_text}"
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test_with_config(
+        app,
+        TestingConfig {
+            virtual_clock: true,
+            ..TestingConfig::default()
+        },
+    );
+
+    let root = utils.root().get(0);
+    let target = root.get(0);
+    let double_clicks = root.get(1);
+    let click_counts = root.get(2);
+
+    utils.click(&target).await;
+    assert_eq!(double_clicks.get(0).text(), Some(""));
+
+    utils.click(&target).await;
+    assert_eq!(double_clicks.get(0).text(), Some("child parent"));
+
+    // The third click is a triple click, not another double click
+    utils.click(&target).await;
+    assert_eq!(double_clicks.get(0).text(), Some("child parent"));
+    assert_eq!(click_counts.get(0).text(), Some("[1, 2, 3]"));
+}
+
+#[tokio::test]
+pub async fn slow_or_distant_clicks_are_not_double_clicks() {
+    fn app() -> Element {
+        let mut double_clicks = use_signal(|| 0);
+        let mut click_counts = use_signal(Vec::<u32>::new);
+        let click_counts_text = format!("{:?}", click_counts.read());
+
+        rsx!(
+            rect {
+                height: "100",
+                width: "100",
+                onclick: move |e: MouseEvent| {
+                    click_counts.write().push(e.get_click_count());
+                },
+                ondoubleclick: move |_: MouseEvent| {
+                    double_clicks += 1;
+                },
+                label {
+                    "{double_clicks} {click_counts_text}"
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test_with_config(
+        app,
+        TestingConfig {
+            virtual_clock: true,
+            ..TestingConfig::default()
+        },
+    );
+
+    let label = utils.root().get(0).get(0);
+
+    fn click(utils: &mut TestingHandler, cursor: (f64, f64)) {
+        for name in [EventName::MouseDown, EventName::Click] {
+            utils.push_event(PlatformEvent::Mouse {
+                name,
+                cursor: cursor.into(),
+                button: Some(MouseButton::Left),
+            });
+        }
+    }
+
+    click(&mut utils, (10.0, 10.0));
+    utils.wait_for_update().await;
+    utils.advance_time(DOUBLE_CLICK_INTERVAL * 2).await;
+
+    click(&mut utils, (10.0, 10.0));
+    utils.wait_for_update().await;
+
+    click(&mut utils, (50.0, 50.0));
+    utils.wait_for_update().await;
+
+    assert_eq!(label.get(0).text(), Some("0 [1, 1, 1]"));
+}
+
+#[tokio::test]
+pub async fn custom_double_click_interval() {
+    fn app() -> Element {
+        let mut double_clicks = use_signal(|| 0);
+
+        rsx!(
+            rect {
+                height: "100",
+                width: "100",
+                ondoubleclick: move |_: MouseEvent| {
+                    double_clicks += 1;
+                },
+                label {
+                    "{double_clicks}"
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test_with_config(
+        app,
+        TestingConfig {
+            virtual_clock: true,
+            double_click_interval: Duration::from_millis(100),
+            ..TestingConfig::default()
+        },
+    );
+
+    let target = utils.root().get(0);
+    let label = target.get(0);
+
+    // Quicker than the default interval but slower than the configured one
+    utils.click(&target).await;
+    utils.advance_time(Duration::from_millis(200)).await;
+    utils.click(&target).await;
+    assert_eq!(label.get(0).text(), Some("0"));
+
+    utils.click(&target).await;
+    assert_eq!(label.get(0).text(), Some("1"));
+}
+
+#[tokio::test]
+pub async fn prevent_default() {
+    fn app() -> Element {
+        let mut trap = use_focus();
+        let mut other = use_focus();
+
+        rsx!(
+            rect {
+                width: "100",
+                height: "100",
+                focus_id: trap.attribute(),
+                onclick: move |_| trap.focus(),
+                // Keep the focus when navigating with the keyboard
+                onkeydown: move |e: KeyboardEvent| {
+                    if trap.is_focused() {
+                        e.prevent_default();
+                    }
+                },
+            }
+            rect {
+                width: "100",
+                height: "100",
+                focus_id: other.attribute(),
+                onclick: move |_| other.focus(),
+            }
+        )
+    }
+
+    let mut utils = launch_test(app);
+    utils.wait_for_update().await;
+
+    let other = utils.root().get(1);
+    utils.click(&other).await;
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+    let other_id = utils.focus_id();
+
+    // The focus moves from `other` to `trap`
+    utils.press_key(Key::Tab, Modifiers::empty()).await;
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+    let trap_id = utils.focus_id();
+    assert_ne!(trap_id, other_id);
+
+    // But it can't leave `trap`, as its `onkeydown` runs before the one of `KeyboardNavigator`
+    utils.press_key(Key::Tab, Modifiers::empty()).await;
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+    assert_eq!(utils.focus_id(), trap_id);
+}
+
+#[tokio::test]
+pub async fn capture_events_go_top_down() {
+    fn app() -> Element {
+        let mut events = use_signal::<Vec<&'static str>>(Vec::new);
+
+        let text = events.read().join(" ");
+
+        rsx!(
+            rect {
+                height: "100%",
+                width: "100%",
+                onclickcapture: move |_| events.write().push("outer-capture"),
+                onclick: move |_| events.write().push("outer"),
+                rect {
+                    height: "100",
+                    width: "100",
+                    onclickcapture: move |_| events.write().push("inner-capture"),
+                    onclick: move |_| events.write().push("inner"),
+                }
+                label {
+                    "{text}"
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(app);
+
+    let label = utils.root().get(0).get(1);
+
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::Click,
+        cursor: CursorPoint::new(50.0, 50.0),
+        button: Some(MouseButton::Left),
+    });
+
+    utils.wait_for_update().await;
+
+    assert_eq!(
+        label.get(0).text(),
+        Some("outer-capture inner-capture inner outer")
+    );
+}
+
+#[tokio::test]
+pub async fn capture_events_can_stop_propagation() {
+    fn app() -> Element {
+        let mut events = use_signal::<Vec<&'static str>>(Vec::new);
+
+        let text = events.read().join(" ");
+
+        rsx!(
+            rect {
+                height: "100%",
+                width: "100%",
+                onkeydowncapture: move |e: KeyboardEvent| {
+                    events.write().push("capture");
+                    if e.key == Key::Escape {
+                        e.stop_capture_propagation();
+                    }
+                },
+                rect {
+                    height: "100",
+                    width: "100",
+                    onkeydown: move |_| events.write().push("keydown"),
+                }
+                label {
+                    "{text}"
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(app);
+
+    let label = utils.root().get(0).get(1);
+
+    utils.press_key(Key::Enter, Modifiers::empty()).await;
+    utils.wait_for_update().await;
+    assert_eq!(label.get(0).text(), Some("capture keydown"));
+
+    // The child never gets the keys stopped by the parent
+    utils.press_key(Key::Escape, Modifiers::empty()).await;
+    utils.wait_for_update().await;
+    assert_eq!(label.get(0).text(), Some("capture keydown capture"));
+}
+
+#[tokio::test]
+pub async fn bubbling_listeners_cant_stop_capture_propagation() {
+    fn app() -> Element {
+        let mut events = use_signal::<Vec<String>>(Vec::new);
+
+        let text = events.read().join(" ");
+
+        rsx!(
+            rect {
+                height: "100%",
+                width: "100%",
+                onclick: move |e: MouseEvent| {
+                    events
+                        .write()
+                        .push(format!("outer-{}", e.is_capture_propagation_stopped()));
+                },
+                rect {
+                    height: "100",
+                    width: "100",
+                    onclick: move |e: MouseEvent| {
+                        events.write().push("inner".to_string());
+                        e.stop_capture_propagation();
+                    },
+                }
+                label {
+                    "{text}"
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(app);
+
+    let label = utils.root().get(0).get(1);
+
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::Click,
+        cursor: CursorPoint::new(50.0, 50.0),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+
+    // The ancestors still get the event, only `stop_propagation` stops the bubbling
+    assert_eq!(label.get(0).text(), Some("inner outer-true"));
+}
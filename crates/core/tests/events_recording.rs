@@ -0,0 +1,43 @@
+use freya_core::prelude::*;
+use freya_elements::events::keyboard::Key;
+use std::path::Path;
+
+#[test]
+fn parse_events_recording() {
+    let text = "window_size 600 400
+scale_factor 2
+0 mouse mouseover 10.5 20 none
+16 mouse click 10.5 20 left
+40 wheel wheel 0 -53 10 20
+50 keyboard keydown char:a%20b KeyA 0
+60 keyboard keyup Enter Enter 4
+70 touch touchmove 1 2 3 moved calibrated:1:2:none
+80 file filedrop 1 2 /tmp/a%20b
+90 resize 800 600
+";
+
+    let recording = EventsRecording::parse(text).unwrap();
+    assert_eq!(recording.window_size, (600.0, 400.0).into());
+    assert_eq!(recording.scale_factor, 2.0);
+    assert_eq!(recording.entries.len(), 8);
+    assert_eq!(recording.to_string(), text);
+
+    let RecordedEvent::Platform(PlatformEvent::Keyboard { key, .. }) = &recording.entries[3].event
+    else {
+        panic!("Expected a keyboard event");
+    };
+    assert_eq!(key, &Key::Character("a b".to_string()));
+
+    let RecordedEvent::Platform(PlatformEvent::File { file_path, .. }) =
+        &recording.entries[6].event
+    else {
+        panic!("Expected a file event");
+    };
+    assert_eq!(file_path.as_deref(), Some(Path::new("/tmp/a b")));
+
+    assert_eq!(
+        EventsRecording::parse("window_size 600 400\nscale_factor 2\n0 mouse unknown 1 1 none")
+            .unwrap_err(),
+        ParseEventsRecordingError { line: 3 }
+    );
+}
@@ -0,0 +1,83 @@
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+#[tokio::test]
+pub async fn focus_events() {
+    #[component]
+    fn Child(name: &'static str, events: Signal<Vec<String>>) -> Element {
+        let mut events = events;
+        let mut focus = use_focus();
+
+        rsx!(rect {
+            width: "100%",
+            height: "50",
+            focus_id: focus.attribute(),
+            onclick: move |_| focus.focus(),
+            onfocus: move |_| events.push(format!("focus {name}")),
+            onblur: move |_| events.push(format!("blur {name}")),
+        })
+    }
+
+    fn app() -> Element {
+        let mut events = use_signal(Vec::<String>::new);
+
+        rsx!(
+            rect {
+                width: "100%",
+                height: "100%",
+                onfocusin: move |_| events.push("focusin".to_string()),
+                onfocusout: move |_| events.push("focusout".to_string()),
+                Child { name: "a", events }
+                Child { name: "b", events }
+                label { "{events:?}" }
+            }
+        )
+    }
+
+    let mut utils = launch_test(app);
+    utils.wait_for_update().await;
+
+    let root = utils.root().get(0);
+    let label = root.get(2);
+
+    // Focus with the mouse
+    utils.click(&root.get(0)).await;
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+    assert_eq!(
+        label.get(0).text(),
+        Some(format!("{:?}", ["focus a", "focusin"]).as_str())
+    );
+
+    utils.click(&root.get(1)).await;
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+    assert_eq!(
+        label.get(0).text(),
+        Some(
+            format!(
+                "{:?}",
+                ["focus a", "focusin", "blur a", "focusout", "focus b", "focusin"]
+            )
+            .as_str()
+        )
+    );
+
+    // Focus with the keyboard
+    utils.press_key(Key::Tab, Modifiers::empty()).await;
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+    assert_eq!(
+        label.get(0).text(),
+        Some(
+            format!(
+                "{:?}",
+                [
+                    "focus a", "focusin", "blur a", "focusout", "focus b", "focusin", "blur b",
+                    "focusout", "focus a", "focusin"
+                ]
+            )
+            .as_str()
+        )
+    );
+}
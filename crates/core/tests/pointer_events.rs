@@ -189,3 +189,126 @@ pub async fn pointer_events_from_touch() {
         Some(format!("{:?}", vec!["enter", "over", "down", "up"]).as_str())
     );
 }
+
+#[tokio::test]
+pub async fn mouse_up_and_pointer_move() {
+    fn app() -> Element {
+        let mut state = use_signal(Vec::<String>::new);
+
+        rsx!(
+            rect {
+                height: "100%",
+                width: "100%",
+                onmouseup: move |e: MouseEvent| state.push(format!("up {:?}", e.get_trigger_button())),
+                onpointermove: move |e: PointerEvent| {
+                    let coordinates = e.get_element_coordinates();
+                    state.push(format!("move {},{}", coordinates.x, coordinates.y))
+                },
+                label { "{state:?}" }
+            }
+        )
+    }
+
+    let mut utils = launch_test(app);
+
+    let label = utils.root().get(0).get(0);
+
+    for cursor in [(10.0, 10.0), (20.0, 30.0)] {
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::MouseOver,
+            cursor: cursor.into(),
+            button: None,
+        });
+        utils.wait_for_update().await;
+    }
+
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::RightClick,
+        cursor: (20.0, 30.0).into(),
+        button: Some(MouseButton::Right),
+    });
+    utils.wait_for_update().await;
+
+    assert_eq!(
+        label.get(0).text(),
+        Some(format!("{:?}", vec!["move 10,10", "move 20,30", "up Some(Right)"]).as_str())
+    );
+}
+
+#[tokio::test]
+pub async fn pointer_capture() {
+    fn app() -> Element {
+        let mut state = use_signal(Vec::<String>::new);
+
+        rsx!(
+            rect {
+                height: "100%",
+                width: "100%",
+                direction: "horizontal",
+                rect {
+                    height: "100",
+                    width: "100",
+                    onpointerdown: move |e: PointerEvent| {
+                        e.set_pointer_capture();
+                        state.push("down".to_string())
+                    },
+                    onpointermove: move |e: PointerEvent| {
+                        let coordinates = e.get_element_coordinates();
+                        state.push(format!("move {},{}", coordinates.x, coordinates.y))
+                    },
+                    onpointerup: move |_| state.push("up".to_string()),
+                }
+                rect {
+                    height: "100",
+                    width: "100",
+                    onpointermove: move |_| state.push("other move".to_string()),
+                }
+                label { "{state:?}" }
+            }
+        )
+    }
+
+    let mut utils = launch_test(app);
+
+    let label = utils.root().get(0).get(2);
+
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::MouseDown,
+        cursor: (50.0, 50.0).into(),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+
+    // The captured Node keeps receiving the events outside of its bounds
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::MouseOver,
+        cursor: (150.0, 50.0).into(),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::Click,
+        cursor: (150.0, 50.0).into(),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+
+    assert_eq!(
+        label.get(0).text(),
+        Some(format!("{:?}", vec!["down", "move 150,50", "up"]).as_str())
+    );
+
+    // The pointer was released
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::MouseOver,
+        cursor: (160.0, 50.0).into(),
+        button: None,
+    });
+    utils.wait_for_update().await;
+
+    assert_eq!(
+        label.get(0).text(),
+        Some(format!("{:?}", vec!["down", "move 150,50", "up", "other move"]).as_str())
+    );
+}
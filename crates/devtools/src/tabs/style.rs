@@ -8,7 +8,7 @@ use freya_native_core::NodeId;
 use crate::{
     hooks::use_selected_node,
     property::{
-        BorderProperty, ColorProperty, LinearGradientProperty, Property, ShadowProperty,
+        BorderProperty, ColorProperty, GradientProperty, Property, ShadowProperty,
         TextShadowProperty,
     },
     NodeInspectorBar,
@@ -83,9 +83,9 @@ pub fn NodeInspectorStyle(node_id: NodeId) -> Element {
                                     }
                                 }
                             }
-                            AttributeType::LinearGradient(fill) => {
+                            AttributeType::Gradient(fill) => {
                                 rsx!{
-                                    LinearGradientProperty {
+                                    GradientProperty {
                                         key: "{i}",
                                         name: "{name}",
                                         fill: fill.clone()
@@ -86,6 +86,40 @@
 //! }
 //! ```
 //!
+//! #### flex
+//! Share the remaining space of the parent's main axis with the other `flex` siblings, proportionally to the given weight.
+//! It's measured after the rest of siblings and it respects the `min_*` and `max_*` attributes.
+//! When used in the cross axis it behaves like `fill`.
+//!
+//! ```rust, no_run
+//! # use freya::prelude::*;
+//! fn app() -> Element {
+//!     rsx!(
+//!         rect {
+//!             width: "100%",
+//!             height: "100%",
+//!             direction: "horizontal",
+//!             rect {
+//!                 width: "flex(1)", // 1/4 of the remaining space
+//!                 height: "100%",
+//!             }
+//!             rect {
+//!                 width: "flex(2)", // 2/4 of the remaining space
+//!                 height: "100%",
+//!             }
+//!             rect {
+//!                 width: "flex(1)", // 1/4 of the remaining space
+//!                 height: "100%",
+//!             }
+//!             rect {
+//!                 width: "200", // Measured first
+//!                 height: "100%",
+//!             }
+//!         }
+//!     )
+//! }
+//! ```
+//!
 //! #### fill-min
 //! Will have the same size of the biggest sibling element inside a container who has `content: fit`.
 //! For an example, see `content`.
@@ -186,9 +186,16 @@ builder_constructors! {
         max_width: String,
         #[doc = include_str!("_docs/attributes/background.md")]
         background: String,
+        #[doc = include_str!("_docs/attributes/image_fill.md")]
+        image_data: String,
+        image_fill: String,
         #[doc = include_str!("_docs/attributes/border.md")]
         border: String,
         border_align: String,
+        border_top: String,
+        border_right: String,
+        border_bottom: String,
+        border_left: String,
         #[doc = include_str!("_docs/attributes/direction.md")]
         direction: String,
         #[doc = include_str!("_docs/attributes/shadow.md")]
@@ -229,6 +236,19 @@ builder_constructors! {
         opacity: String,
         #[doc = include_str!("_docs/attributes/content.md")]
         content: String,
+        #[doc = include_str!("_docs/attributes/wrap_content.md")]
+        wrap_content: String,
+        #[doc = include_str!("_docs/attributes/spacing.md")]
+        spacing: String,
+        #[doc = include_str!("_docs/attributes/grid.md")]
+        grid_columns: String,
+        grid_rows: String,
+        grid_column: String,
+        grid_row: String,
+        #[doc = include_str!("_docs/attributes/align_self.md")]
+        align_self: String,
+        #[doc = include_str!("_docs/attributes/aspect_ratio.md")]
+        aspect_ratio: String,
 
         name: String,
         focusable: String,
@@ -299,6 +319,13 @@ builder_constructors! {
         margin: String,
         #[doc = include_str!("_docs/attributes/opacity.md")]
         opacity: String,
+        #[doc = include_str!("_docs/attributes/grid.md")]
+        grid_column: String,
+        grid_row: String,
+        #[doc = include_str!("_docs/attributes/align_self.md")]
+        align_self: String,
+        #[doc = include_str!("_docs/attributes/aspect_ratio.md")]
+        aspect_ratio: String,
 
         layer: String,
         role: String,
@@ -371,6 +398,13 @@ builder_constructors! {
         margin: String,
         #[doc = include_str!("_docs/attributes/opacity.md")]
         opacity: String,
+        #[doc = include_str!("_docs/attributes/grid.md")]
+        grid_column: String,
+        grid_row: String,
+        #[doc = include_str!("_docs/attributes/align_self.md")]
+        align_self: String,
+        #[doc = include_str!("_docs/attributes/aspect_ratio.md")]
+        aspect_ratio: String,
 
         layer: String,
         cursor_index: String,
@@ -444,6 +478,13 @@ builder_constructors! {
         rotate: String,
         #[doc = include_str!("_docs/attributes/opacity.md")]
         opacity: String,
+        #[doc = include_str!("_docs/attributes/grid.md")]
+        grid_column: String,
+        grid_row: String,
+        #[doc = include_str!("_docs/attributes/align_self.md")]
+        align_self: String,
+        #[doc = include_str!("_docs/attributes/aspect_ratio.md")]
+        aspect_ratio: String,
 
         image_data: String,
         image_reference: String,
@@ -483,6 +524,13 @@ builder_constructors! {
         rotate: String,
         #[doc = include_str!("_docs/attributes/opacity.md")]
         opacity: String,
+        #[doc = include_str!("_docs/attributes/grid.md")]
+        grid_column: String,
+        grid_row: String,
+        #[doc = include_str!("_docs/attributes/align_self.md")]
+        align_self: String,
+        #[doc = include_str!("_docs/attributes/aspect_ratio.md")]
+        aspect_ratio: String,
 
         svg_data: String,
         svg_content: String,
@@ -572,16 +620,26 @@ pub mod events {
 
         #[doc = include_str!("_docs/events/click.md")]
         onclick
+        #[doc = include_str!("_docs/events/clickcapture.md")]
+        onclickcapture
         #[doc = include_str!("_docs/events/globalclick.md")]
         onglobalclick
         #[doc = include_str!("_docs/events/onmiddleclick.md")]
         onmiddleclick
         #[doc = include_str!("_docs/events/onrightclick.md")]
         onrightclick
+        #[doc = include_str!("_docs/events/ondoubleclick.md")]
+        ondoubleclick
         #[doc = include_str!("_docs/events/mousedown.md")]
         onmousedown
+        #[doc = include_str!("_docs/events/mousedowncapture.md")]
+        onmousedowncapture
         #[doc = include_str!("_docs/events/globalmousedown.md")]
         onglobalmousedown
+        #[doc = include_str!("_docs/events/mouseup.md")]
+        onmouseup
+        #[doc = include_str!("_docs/events/mouseupcapture.md")]
+        onmouseupcapture
         #[doc = include_str!("_docs/events/mouseover.md")]
         onmouseover
         #[doc = include_str!("_docs/events/globalmouseover.md")]
@@ -597,6 +655,8 @@ pub mod events {
 
         #[doc = include_str!("_docs/events/wheel.md")]
         onwheel
+        #[doc = include_str!("_docs/events/wheelcapture.md")]
+        onwheelcapture
     ];
 
     impl_event! [
@@ -604,8 +664,12 @@ pub mod events {
 
         #[doc = include_str!("_docs/events/keydown.md")]
         onkeydown
+        #[doc = include_str!("_docs/events/keydowncapture.md")]
+        onkeydowncapture
         #[doc = include_str!("_docs/events/keyup.md")]
         onkeyup
+        #[doc = include_str!("_docs/events/keyupcapture.md")]
+        onkeyupcapture
     ];
 
     impl_event! [
@@ -626,12 +690,18 @@ pub mod events {
 
         #[doc = include_str!("_docs/events/pointerdown.md")]
         onpointerdown
+        #[doc = include_str!("_docs/events/pointerdowncapture.md")]
+        onpointerdowncapture
         #[doc = include_str!("_docs/events/pointerup.md")]
         onpointerup
+        #[doc = include_str!("_docs/events/pointerupcapture.md")]
+        onpointerupcapture
         #[doc = include_str!("_docs/events/onglobalpointerup.md")]
         onglobalpointerup
         #[doc = include_str!("_docs/events/pointerover.md")]
         onpointerover
+        #[doc = include_str!("_docs/events/pointermove.md")]
+        onpointermove
         #[doc = include_str!("_docs/events/pointerenter.md")]
         onpointerenter
         #[doc = include_str!("_docs/events/pointerleave.md")]
@@ -648,4 +718,17 @@ pub mod events {
         #[doc = include_str!("_docs/events/onglobalfilehovercancelled.md")]
         onglobalfilehovercancelled
     ];
+
+    impl_event! [
+        FocusData;
+
+        #[doc = include_str!("_docs/events/focus.md")]
+        onfocus
+        #[doc = include_str!("_docs/events/blur.md")]
+        onblur
+        #[doc = include_str!("_docs/events/focusin.md")]
+        onfocusin
+        #[doc = include_str!("_docs/events/focusout.md")]
+        onfocusout
+    ];
 }
@@ -1,4 +1,6 @@
+pub mod event_flags;
 pub mod file;
+pub mod focus;
 pub mod keyboard;
 pub mod mouse;
 pub mod pointer;
@@ -6,7 +8,9 @@ pub mod touch;
 pub mod wheel;
 
 use dioxus_core::Event;
+pub use event_flags::*;
 pub use file::*;
+pub use focus::*;
 pub use keyboard::*;
 pub use mouse::*;
 pub use pointer::*;
@@ -18,3 +22,4 @@ pub type MouseEvent = Event<MouseData>;
 pub type WheelEvent = Event<WheelData>;
 pub type TouchEvent = Event<TouchData>;
 pub type PointerEvent = Event<PointerData>;
+pub type FocusEvent = Event<FocusData>;
@@ -0,0 +1,96 @@
+use std::fmt::{self, Debug};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+#[derive(Default)]
+struct Flags {
+    default_prevented: AtomicBool,
+    propagation_stopped: AtomicBool,
+}
+
+/// Flags shared by all the listeners of the same event, including its capture variant,
+/// so any of them can cancel its default behaviour or stop it from reaching the rest.
+#[derive(Clone, Default)]
+pub struct EventFlags(Arc<Flags>);
+
+impl EventFlags {
+    /// Cancel the default behaviour of the event.
+    pub fn prevent_default(&self) {
+        self.0.default_prevented.store(true, Ordering::Relaxed)
+    }
+
+    /// Check if the default behaviour of the event was cancelled.
+    pub fn is_default_prevented(&self) -> bool {
+        self.0.default_prevented.load(Ordering::Relaxed)
+    }
+
+    /// Stop the event from reaching the listeners that haven't received it yet.
+    pub fn stop_propagation(&self) {
+        self.0.propagation_stopped.store(true, Ordering::Relaxed)
+    }
+
+    /// Check if the event was stopped.
+    pub fn is_propagation_stopped(&self) -> bool {
+        self.0.propagation_stopped.load(Ordering::Relaxed)
+    }
+}
+
+impl Debug for EventFlags {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EventFlags")
+            .field("default_prevented", &self.is_default_prevented())
+            .field("propagation_stopped", &self.is_propagation_stopped())
+            .finish()
+    }
+}
+
+impl PartialEq for EventFlags {
+    fn eq(&self, other: &Self) -> bool {
+        self.is_default_prevented() == other.is_default_prevented()
+            && self.is_propagation_stopped() == other.is_propagation_stopped()
+    }
+}
+
+macro_rules! impl_event_flags {
+    ($($data:ty),*) => {
+        $(
+            impl $data {
+                /// Cancel the default behaviour of this event, e.g. the keyboard navigation when pressing `Tab`.
+                /// Listeners that run later, like the ones of the ancestors, can check it with `is_default_prevented`.
+                ///
+                /// This doesn't stop the event from bubbling, call `stop_propagation` on the event for that,
+                /// e.g. so clicking a tile nested in another one doesn't trigger the action of the outer tile.
+                pub fn prevent_default(&self) {
+                    self.flags.prevent_default()
+                }
+
+                /// Check if a listener cancelled the default behaviour of this event.
+                pub fn is_default_prevented(&self) -> bool {
+                    self.flags.is_default_prevented()
+                }
+
+                /// Stop this event from reaching the capture listeners of the descendants and all the regular listeners,
+                /// e.g. from an `onkeydowncapture` listener so the `onkeydown` listeners of the descendants never get it.
+                ///
+                /// Regular listeners run as a single bubbling dispatch, so calling this from one of them, e.g. an `onclick`,
+                /// doesn't skip the listeners of its ancestors. Use `stop_propagation` on the event for that.
+                pub fn stop_capture_propagation(&self) {
+                    self.flags.stop_propagation()
+                }
+
+                /// Check if a capture listener stopped this event from reaching the rest of listeners.
+                pub fn is_capture_propagation_stopped(&self) -> bool {
+                    self.flags.is_propagation_stopped()
+                }
+            }
+        )*
+    };
+}
+
+impl_event_flags!(
+    super::KeyboardData,
+    super::MouseData,
+    super::PointerData,
+    super::TouchData,
+    super::WheelData
+);
@@ -0,0 +1,11 @@
+use crate::definitions::PlatformEventData;
+
+/// Data of a Focus event.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct FocusData;
+
+impl From<&PlatformEventData> for FocusData {
+    fn from(val: &PlatformEventData) -> Self {
+        val.downcast::<FocusData>().cloned().unwrap()
+    }
+}
@@ -1,3 +1,4 @@
+use super::EventFlags;
 use crate::definitions::PlatformEventData;
 pub use keyboard_types::{Code, Key, Modifiers};
 use winit::keyboard::{self, NamedKey};
@@ -314,6 +315,7 @@ pub struct KeyboardData {
     pub key: Key,
     pub code: Code,
     pub modifiers: Modifiers,
+    pub flags: EventFlags,
 }
 
 impl KeyboardData {
@@ -322,6 +324,7 @@ impl KeyboardData {
             key,
             code,
             modifiers,
+            flags: EventFlags::default(),
         }
     }
 }
@@ -1,6 +1,7 @@
 use torin::geometry::CursorPoint;
 pub use winit::event::MouseButton;
 
+use super::EventFlags;
 use crate::definitions::PlatformEventData;
 
 /// Data of a Mouse event.
@@ -9,6 +10,8 @@ pub struct MouseData {
     pub screen_coordinates: CursorPoint,
     pub element_coordinates: CursorPoint,
     pub trigger_button: Option<MouseButton>,
+    pub click_count: u32,
+    pub flags: EventFlags,
 }
 
 impl MouseData {
@@ -16,11 +19,14 @@ impl MouseData {
         screen_coordinates: CursorPoint,
         element_coordinates: CursorPoint,
         trigger_button: Option<MouseButton>,
+        click_count: u32,
     ) -> Self {
         Self {
             screen_coordinates,
             element_coordinates,
             trigger_button,
+            click_count,
+            flags: EventFlags::default(),
         }
     }
 }
@@ -40,6 +46,13 @@ impl MouseData {
     pub fn get_trigger_button(&self) -> Option<MouseButton> {
         self.trigger_button
     }
+
+    /// Get the number of consecutive clicks of the trigger button, e.g `2` for the second press of a double click.
+    ///
+    /// Only `mousedown` and click events count the clicks, it's `0` for the rest.
+    pub fn get_click_count(&self) -> u32 {
+        self.click_count
+    }
 }
 
 impl From<&PlatformEventData> for MouseData {
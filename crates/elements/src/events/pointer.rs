@@ -1,7 +1,11 @@
+use std::fmt::{self, Debug};
+use std::sync::Arc;
+
 use torin::geometry::CursorPoint;
 pub use winit::event::MouseButton;
 use winit::event::{Force, TouchPhase};
 
+use super::EventFlags;
 use crate::definitions::PlatformEventData;
 
 /// The type of device that triggered a Pointer event.
@@ -17,12 +21,38 @@ pub enum PointerType {
     },
 }
 
+/// Captures or releases the pointer for the Node that received a Pointer event.
+#[derive(Clone, Default)]
+pub struct PointerCapture(Option<Arc<dyn Fn(bool) + Send + Sync>>);
+
+impl PointerCapture {
+    /// Create a new [PointerCapture] given a callback that will be called with `true` to capture and `false` to release.
+    pub fn new(callback: impl Fn(bool) + Send + Sync + 'static) -> Self {
+        Self(Some(Arc::new(callback)))
+    }
+}
+
+impl Debug for PointerCapture {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("PointerCapture")
+    }
+}
+
+impl PartialEq for PointerCapture {
+    fn eq(&self, _other: &Self) -> bool {
+        // Only the data of the events is compared
+        true
+    }
+}
+
 /// Data of a Mouse event.
 #[derive(Debug, Clone, PartialEq)]
 pub struct PointerData {
     pub screen_coordinates: CursorPoint,
     pub element_coordinates: CursorPoint,
     pub point_type: PointerType,
+    pub pointer_capture: PointerCapture,
+    pub flags: EventFlags,
 }
 
 impl PointerData {
@@ -35,6 +65,8 @@ impl PointerData {
             screen_coordinates,
             element_coordinates,
             point_type,
+            pointer_capture: PointerCapture::default(),
+            flags: EventFlags::default(),
         }
     }
 }
@@ -54,6 +86,21 @@ impl PointerData {
     pub fn get_pointer_type(&self) -> PointerType {
         self.point_type
     }
+
+    /// Capture the pointer, so the Node that received this event keeps receiving the Pointer events wherever the pointer goes,
+    /// until the pointer is released (e.g. `pointerup`) or [PointerData::release_pointer_capture] is called.
+    pub fn set_pointer_capture(&self) {
+        if let Some(callback) = &self.pointer_capture.0 {
+            callback(true)
+        }
+    }
+
+    /// Release the pointer previously captured with [PointerData::set_pointer_capture].
+    pub fn release_pointer_capture(&self) {
+        if let Some(callback) = &self.pointer_capture.0 {
+            callback(false)
+        }
+    }
 }
 
 impl From<&PlatformEventData> for PointerData {
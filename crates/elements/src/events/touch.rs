@@ -1,6 +1,7 @@
 use torin::geometry::CursorPoint;
 pub use winit::event::{Force, TouchPhase};
 
+use super::EventFlags;
 use crate::definitions::PlatformEventData;
 
 /// Data of a Touch event.
@@ -11,6 +12,7 @@ pub struct TouchData {
     pub finger_id: u64,
     pub phase: TouchPhase,
     pub force: Option<Force>,
+    pub flags: EventFlags,
 }
 
 impl TouchData {
@@ -27,6 +29,7 @@ impl TouchData {
             finger_id,
             phase,
             force,
+            flags: EventFlags::default(),
         }
     }
 
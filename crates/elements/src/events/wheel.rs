@@ -1,3 +1,4 @@
+use super::EventFlags;
 use crate::definitions::PlatformEventData;
 
 /// Data of a Wheel event.
@@ -6,11 +7,16 @@ pub struct WheelData {
     #[allow(dead_code)]
     delta_x: f64,
     delta_y: f64,
+    pub flags: EventFlags,
 }
 
 impl WheelData {
     pub fn new(delta_x: f64, delta_y: f64) -> Self {
-        Self { delta_x, delta_y }
+        Self {
+            delta_x,
+            delta_y,
+            flags: EventFlags::default(),
+        }
     }
 }
 
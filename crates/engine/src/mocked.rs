@@ -140,8 +140,33 @@ impl Shader {
     ) -> Option<Self> {
         unimplemented!("This is mocked")
     }
+
+    pub fn radial_gradient<'a>(
+        _center: impl Into<Point>,
+        _radius: f32,
+        _colors: impl Into<GradientShaderColors<'a>>,
+        _pos: impl Into<Option<&'a [f32]>>,
+        _mode: TileMode,
+        _flags: impl Into<Option<GradientFlags>>,
+        _local_matrix: impl Into<Option<&'a Matrix>>,
+    ) -> Option<Self> {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn sweep_gradient<'a>(
+        _center: impl Into<Point>,
+        _colors: impl Into<GradientShaderColors<'a>>,
+        _pos: impl Into<Option<&'a [f32]>>,
+        _mode: TileMode,
+        _angles: impl Into<Option<(f32, f32)>>,
+        _flags: impl Into<Option<GradientFlags>>,
+        _local_matrix: impl Into<Option<&'a Matrix>>,
+    ) -> Option<Self> {
+        unimplemented!("This is mocked")
+    }
 }
 
+#[derive(Copy, Clone)]
 pub enum TileMode {
     Clamp = 0,
     Repeat = 1,
@@ -171,6 +196,14 @@ impl Matrix {
     pub fn set_rotate(&mut self, _degrees: f32, _pivot: impl Into<Option<Point>>) -> &mut Self {
         unimplemented!("This is mocked")
     }
+
+    pub fn translate(_d: impl Into<Point>) -> Self {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn pre_scale(&mut self, _s: (f32, f32), _p: impl Into<Option<Point>>) -> &mut Self {
+        unimplemented!("This is mocked")
+    }
 }
 
 #[repr(C)]
@@ -604,6 +637,14 @@ impl Paint {
     pub fn set_mask_filter(&mut self, _mask_filter: impl Into<Option<MaskFilter>>) -> &mut Self {
         unimplemented!("This is mocked")
     }
+
+    pub fn set_stroke_cap(&mut self, _cap: PaintCap) -> &mut Self {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn set_path_effect(&mut self, _path_effect: impl Into<Option<PathEffect>>) -> &mut Self {
+        unimplemented!("This is mocked")
+    }
 }
 
 pub enum PaintStyle {
@@ -612,6 +653,20 @@ pub enum PaintStyle {
     StrokeAndFill = 2,
 }
 
+pub enum PaintCap {
+    Butt = 0,
+    Round = 1,
+    Square = 2,
+}
+
+pub struct PathEffect;
+
+impl PathEffect {
+    pub fn dash(_intervals: &[f32], _phase: f32) -> Option<Self> {
+        unimplemented!("This is mocked")
+    }
+}
+
 pub struct FontStyle;
 
 impl FontStyle {
@@ -1136,6 +1191,44 @@ impl Image {
     pub fn from_encoded(_data: Data) -> Option<Self> {
         unimplemented!("This is mocked")
     }
+
+    pub fn encode<'a>(
+        &self,
+        _context: impl Into<Option<&'a mut DirectContext>>,
+        _format: EncodedImageFormat,
+        _quality: impl Into<Option<u32>>,
+    ) -> Option<Data> {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn width(&self) -> i32 {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn height(&self) -> i32 {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn to_shader<'a>(
+        &self,
+        _tile_modes: impl Into<Option<(TileMode, TileMode)>>,
+        _sampling: impl Into<SamplingOptions>,
+        _local_matrix: impl Into<Option<&'a Matrix>>,
+    ) -> Option<Shader> {
+        unimplemented!("This is mocked")
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+#[repr(i32)]
+pub enum EncodedImageFormat {
+    BMP = 0,
+    GIF = 1,
+    ICO = 2,
+    JPEG = 3,
+    PNG = 4,
+    WBMP = 5,
+    WEBP = 6,
 }
 
 pub struct Data;
@@ -1148,6 +1241,10 @@ impl Data {
     pub unsafe fn new_bytes(_bytes: &[u8]) -> Self {
         unimplemented!("This is mocked")
     }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        unimplemented!("This is mocked")
+    }
 }
 
 #[repr(C)]
@@ -1180,6 +1277,15 @@ impl FilterMode {
     pub const Last: FilterMode = FilterMode::Linear;
 }
 
+#[derive(Copy, Clone, Debug, Default)]
+pub struct SamplingOptions;
+
+impl From<FilterMode> for SamplingOptions {
+    fn from(_filter_mode: FilterMode) -> Self {
+        unimplemented!("This is mocked")
+    }
+}
+
 pub struct Path;
 
 impl Path {
@@ -1374,6 +1480,20 @@ impl Surface {
         unimplemented!("This is mocked")
     }
 
+    pub fn image_snapshot(&mut self) -> Image {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn read_pixels(
+        &mut self,
+        _dst_info: &ImageInfo,
+        _dst_pixels: &mut [u8],
+        _dst_row_bytes: usize,
+        _src_point: impl Into<(i32, i32)>,
+    ) -> bool {
+        unimplemented!("This is mocked")
+    }
+
     pub fn from_backend_render_target(
         _context: &mut RecordingContext,
         _backend_render_target: &BackendRenderTarget,
@@ -1388,6 +1508,28 @@ impl Surface {
 
 pub struct ColorSpace;
 
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+#[repr(i32)]
+pub enum AlphaType {
+    Unknown = 0,
+    Opaque = 1,
+    Premul = 2,
+    Unpremul = 3,
+}
+
+pub struct ImageInfo;
+
+impl ImageInfo {
+    pub fn new(
+        _dimensions: impl Into<(i32, i32)>,
+        _ct: ColorType,
+        _at: AlphaType,
+        _cs: impl Into<Option<ColorSpace>>,
+    ) -> Self {
+        unimplemented!("This is mocked")
+    }
+}
+
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
 #[repr(i32)]
 pub enum ColorType {
@@ -1541,6 +1683,13 @@ impl BackendRenderTarget {
     }
 }
 
+pub mod surfaces {
+    use crate::prelude::*;
+    pub fn raster_n32_premul(size: impl Into<(i32, i32)>) -> Option<Surface> {
+        unimplemented!("This is mocked")
+    }
+}
+
 pub mod backend_render_targets {
     use crate::prelude::*;
     pub fn make_gl(
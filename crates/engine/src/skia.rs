@@ -13,7 +13,7 @@ pub use skia_safe::{
     path::ArcSize,
     rrect::Corner,
     runtime_effect::Uniform,
-    svg,
+    surfaces, svg,
     textlayout::{
         paragraph::GlyphClusterInfo, Decoration, FontCollection, FontFeature, LineMetrics,
         Paragraph, ParagraphBuilder, ParagraphStyle, PlaceholderStyle, PositionWithAffinity,
@@ -21,7 +21,8 @@ pub use skia_safe::{
         TextDecoration, TextDecorationStyle, TextDirection, TextHeightBehavior, TextIndex,
         TextRange, TextShadow, TextStyle, TypefaceFontProvider,
     },
-    BlurStyle, Canvas, ClipOp, Color, ColorSpace, ColorType, Data, FilterMode, FontArguments,
-    FontMgr, FontStyle, IRect, Image, MaskFilter, Matrix, Paint, PaintStyle, Path, PathDirection,
-    Point, RRect, Rect, RuntimeEffect, Shader, Surface, TileMode, Typeface, HSV, RGB,
+    AlphaType, BlurStyle, Canvas, ClipOp, Color, ColorSpace, ColorType, Data, EncodedImageFormat,
+    FilterMode, FontArguments, FontMgr, FontStyle, IRect, Image, ImageInfo, MaskFilter, Matrix,
+    Paint, PaintCap, PaintStyle, Path, PathDirection, PathEffect, Point, RRect, Rect,
+    RuntimeEffect, SamplingOptions, Shader, Surface, TileMode, Typeface, HSV, RGB,
 };
@@ -94,6 +94,31 @@
 //! }
 //! ```
 //!
+//! ## Follow the platform light/dark preference
+//!
+//! `ThemeProvider` can switch between a light and a dark theme as the preference of the platform changes.
+//! Platforms that don't report a preference, like most Linux desktops, stay with the light theme.
+//!
+//! ```rust, no_run
+//! # use freya::prelude::*;
+//! fn app() -> Element {
+//!     rsx!(
+//!         ThemeProvider {
+//!             follow_system: true,
+//!             theme: LIGHT_THEME,
+//!             dark_theme: DARK_THEME,
+//!             Button {
+//!                 label {
+//!                     "Report"
+//!                 }
+//!             }
+//!         }
+//!     )
+//! }
+//! ```
+//!
+//! Use the `use_preferred_theme` hook to read the preference yourself.
+//!
 //! ## Change theme for an individual component
 //!
 //! Most built-in components have their own theme "override."
@@ -180,3 +205,87 @@
 //!     )
 //! }
 //! ```
+//!
+//! ## Design tokens
+//!
+//! Component themes reference the colors, radii and spacings of `Theme::tokens` with `key(token_name)`,
+//! e.g. the background of `Button` is `key(surface)` and its padding is `key(spacing_medium) key(spacing_large)`.
+//! So rebranding an app only means changing the tokens:
+//!
+//! ```rust, no_run
+//! # use freya::prelude::*;
+//! const BRAND_THEME: Theme = Theme {
+//!     tokens: DesignTokens {
+//!         primary: Cow::Borrowed("rgb(0, 120, 90)"),
+//!         primary_hover: Cow::Borrowed("rgb(0, 105, 80)"),
+//!         primary_pressed: Cow::Borrowed("rgb(0, 90, 70)"),
+//!         radius_medium: Cow::Borrowed("12"),
+//!         ..LIGHT_THEME.tokens
+//!     },
+//!     ..LIGHT_THEME
+//! };
+//! ```
+//!
+//! The overrides of individual components can reference them as well, like `background: "key(primary)".into()`.
+//!
+//! A whole theme, including the hover and pressed shades, can also be derived from a few seed colors:
+//!
+//! ```rust, no_run
+//! # use freya::prelude::*;
+//! fn app() -> Element {
+//!     use_init_theme(
+//!         Theme::from_seeds(ThemeSeeds {
+//!             primary: "rgb(0, 120, 90)",
+//!             secondary: "rgb(255, 200, 80)",
+//!             background: "rgb(20, 20, 25)",
+//!             error: "rgb(220, 50, 50)",
+//!         })
+//!         .unwrap(),
+//!     );
+//!
+//!     rsx!(
+//!         Button {
+//!             label {
+//!                 "Report"
+//!             }
+//!         }
+//!     )
+//! }
+//! ```
+//!
+//! `use_theme` gives you the theme with its `key(token_name)` references, so the tokens can also be changed at runtime.
+//! Use `use_get_theme` to read the final values, or `Theme::resolved` for constants like `LIGHT_THEME`.
+//!
+//! ## Load a theme from a file
+//!
+//! Themes can also be loaded at runtime from TOML or JSON files, so they can be tweaked without rebuilding the app.
+//! Only the values that change need to be in the file, the rest are taken from a base theme:
+//!
+//! ```toml
+//! name = "orange"
+//!
+//! [button]
+//! background = "rgb(255, 95, 0)"
+//!
+//! [button.font_theme]
+//! color = "white"
+//! ```
+//!
+//! `use_init_theme_from_file` provides the theme just like `use_init_theme`, and reloads it whenever the file changes:
+//!
+//! ```rust, no_run
+//! # use freya::prelude::*;
+//! fn app() -> Element {
+//!     use_init_theme_from_file("theme.toml", DARK_THEME);
+//!
+//!     rsx!(
+//!         Button {
+//!             label {
+//!                 "Report"
+//!             }
+//!         }
+//!     )
+//! }
+//! ```
+//!
+//! Use `Theme::from_toml`, `Theme::from_json` or `Theme::from_file` to load them yourself.
@@ -16,6 +16,7 @@ mod use_editable;
 mod use_focus;
 mod use_node;
 mod use_platform;
+mod use_preferred_theme;
 mod use_theme;
 
 #[cfg(feature = "use_camera")]
@@ -36,6 +37,7 @@ pub use use_editable::*;
 pub use use_focus::*;
 pub use use_node::*;
 pub use use_platform::*;
+pub use use_preferred_theme::*;
 pub use use_theme::*;
 
 #[cfg(feature = "use_camera")]
@@ -2,7 +2,27 @@ use crate::cow_borrowed;
 use crate::theming::*;
 
 pub const DARK_THEME: Theme = Theme {
-    name: "dark",
+    name: cow_borrowed!("dark"),
+    tokens: DesignTokens {
+        primary: cow_borrowed!("rgb(255, 95, 0)"),
+        primary_hover: cow_borrowed!("rgb(255, 114, 31)"),
+        primary_pressed: cow_borrowed!("rgb(255, 133, 61)"),
+        on_primary: cow_borrowed!("white"),
+        secondary: cow_borrowed!("rgb(234, 221, 255)"),
+        secondary_hover: cow_borrowed!("rgb(237, 225, 255)"),
+        secondary_pressed: cow_borrowed!("rgb(239, 229, 255)"),
+        background: cow_borrowed!("rgb(25, 25, 25)"),
+        surface: cow_borrowed!("rgb(35, 35, 35)"),
+        surface_hover: cow_borrowed!("rgb(45, 45, 45)"),
+        surface_pressed: cow_borrowed!("rgb(55, 55, 55)"),
+        border: cow_borrowed!("rgb(80, 80, 80)"),
+        focus_border: cow_borrowed!("rgb(110, 110, 110)"),
+        error: cow_borrowed!("rgb(242, 184, 181)"),
+        text_primary: cow_borrowed!("white"),
+        text_secondary: cow_borrowed!("rgb(210, 210, 210)"),
+        text_disabled: cow_borrowed!("rgb(120, 120, 120)"),
+        ..LIGHT_THEME.tokens
+    },
     body: BodyTheme {
         background: cow_borrowed!("rgb(25, 25, 25)"),
         color: cow_borrowed!("white"),
@@ -11,17 +31,17 @@ pub const DARK_THEME: Theme = Theme {
     slider: SliderTheme {
         background: cow_borrowed!("rgb(60, 60, 60)"),
         thumb_background: cow_borrowed!("rgb(60, 60, 60)"),
-        thumb_inner_background: cow_borrowed!("rgb(255, 95, 0)"),
+        thumb_inner_background: cow_borrowed!("key(primary)"),
         border_fill: cow_borrowed!("rgb(110, 110, 110)"),
     },
     button: ButtonTheme {
-        background: cow_borrowed!("rgb(35, 35, 35)"),
-        hover_background: cow_borrowed!("rgb(45, 45, 45)"),
+        background: cow_borrowed!("key(surface)"),
+        hover_background: cow_borrowed!("key(surface_hover)"),
         font_theme: FontTheme {
-            color: cow_borrowed!("white"),
+            color: cow_borrowed!("key(text_primary)"),
         },
-        border_fill: cow_borrowed!("rgb(80, 80, 80)"),
-        focus_border_fill: cow_borrowed!("rgb(110, 110, 110)"),
+        border_fill: cow_borrowed!("key(border)"),
+        focus_border_fill: cow_borrowed!("key(focus_border)"),
         shadow: cow_borrowed!("0 4 5 0 rgb(0, 0, 0, 0.1)"),
         padding: LIGHT_THEME.button.padding,
         margin: LIGHT_THEME.button.margin,
@@ -30,15 +50,15 @@ pub const DARK_THEME: Theme = Theme {
         height: LIGHT_THEME.button.height,
     },
     input: InputTheme {
-        background: cow_borrowed!("rgb(35, 35, 35)"),
-        hover_background: cow_borrowed!("rgb(45, 45, 45)"),
+        background: cow_borrowed!("key(surface)"),
+        hover_background: cow_borrowed!("key(surface_hover)"),
         font_theme: FontTheme {
-            color: cow_borrowed!("white"),
+            color: cow_borrowed!("key(text_primary)"),
         },
         placeholder_font_theme: FontTheme {
-            color: cow_borrowed!("rgb(210, 210, 210)"),
+            color: cow_borrowed!("key(text_secondary)"),
         },
-        border_fill: cow_borrowed!("rgb(80, 80, 80)"),
+        border_fill: cow_borrowed!("key(border)"),
         width: LIGHT_THEME.input.width,
         margin: LIGHT_THEME.input.margin,
         corner_radius: LIGHT_THEME.input.corner_radius,
@@ -46,13 +66,13 @@ pub const DARK_THEME: Theme = Theme {
     switch: SwitchTheme {
         background: cow_borrowed!("rgb(60, 60, 60)"),
         thumb_background: cow_borrowed!("rgb(200, 200, 200)"),
-        enabled_background: cow_borrowed!("rgb(255, 95, 0)"),
-        enabled_thumb_background: cow_borrowed!("rgb(234, 221, 255)"),
-        focus_border_fill: cow_borrowed!("rgb(110, 110, 110)"),
+        enabled_background: cow_borrowed!("key(primary)"),
+        enabled_thumb_background: cow_borrowed!("key(secondary)"),
+        focus_border_fill: cow_borrowed!("key(focus_border)"),
         enabled_focus_border_fill: cow_borrowed!("rgb(170, 170, 170)"),
     },
     scroll_bar: ScrollBarTheme {
-        background: cow_borrowed!("rgb(35, 35, 35)"),
+        background: cow_borrowed!("key(surface)"),
         thumb_background: cow_borrowed!("rgb(100, 100, 100)"),
         hover_thumb_background: cow_borrowed!("rgb(120, 120, 120)"),
         active_thumb_background: cow_borrowed!("rgb(140, 140, 140)"),
@@ -66,30 +86,30 @@ pub const DARK_THEME: Theme = Theme {
     tooltip: TooltipTheme {
         background: cow_borrowed!("rgb(35,35,35)"),
         color: cow_borrowed!("rgb(240,240,240)"),
-        border_fill: cow_borrowed!("rgb(80, 80, 80)"),
+        border_fill: cow_borrowed!("key(border)"),
     },
     dropdown: DropdownTheme {
-        dropdown_background: cow_borrowed!("rgb(25, 25, 25)"),
-        background_button: cow_borrowed!("rgb(35, 35, 35)"),
-        hover_background: cow_borrowed!("rgb(45, 45, 45)"),
+        dropdown_background: cow_borrowed!("key(background)"),
+        background_button: cow_borrowed!("key(surface)"),
+        hover_background: cow_borrowed!("key(surface_hover)"),
         font_theme: FontTheme {
-            color: cow_borrowed!("white"),
+            color: cow_borrowed!("key(text_primary)"),
         },
-        border_fill: cow_borrowed!("rgb(80, 80, 80)"),
+        border_fill: cow_borrowed!("key(border)"),
         arrow_fill: cow_borrowed!("rgb(150, 150, 150)"),
     },
     dropdown_item: DropdownItemTheme {
-        background: cow_borrowed!("rgb(35, 35, 35)"),
+        background: cow_borrowed!("key(surface)"),
         select_background: cow_borrowed!("rgb(80, 80, 80)"),
-        hover_background: cow_borrowed!("rgb(55, 55, 55)"),
+        hover_background: cow_borrowed!("key(surface_pressed)"),
         font_theme: FontTheme {
-            color: cow_borrowed!("white"),
+            color: cow_borrowed!("key(text_primary)"),
         },
     },
     accordion: AccordionTheme {
-        color: cow_borrowed!("white"),
+        color: cow_borrowed!("key(text_primary)"),
         background: cow_borrowed!("rgb(60, 60, 60)"),
-        border_fill: cow_borrowed!("rgb(80, 80, 80)"),
+        border_fill: cow_borrowed!("key(border)"),
     },
     loader: LoaderTheme {
         primary_color: cow_borrowed!("rgb(150, 150, 150)"),
@@ -98,17 +118,17 @@ pub const DARK_THEME: Theme = Theme {
         highlight_color: cow_borrowed!("rgb(43,106,208)"),
     },
     progress_bar: ProgressBarTheme {
-        color: cow_borrowed!("white"),
+        color: cow_borrowed!("key(on_primary)"),
         background: cow_borrowed!("rgb(60, 60, 60)"),
-        progress_background: cow_borrowed!("rgb(255, 95, 0)"),
+        progress_background: cow_borrowed!("key(primary)"),
         width: LIGHT_THEME.progress_bar.width,
         height: LIGHT_THEME.progress_bar.height,
     },
     table: TableTheme {
         font_theme: FontTheme {
-            color: cow_borrowed!("white"),
+            color: cow_borrowed!("key(text_primary)"),
         },
-        background: cow_borrowed!("rgb(25, 25, 25)"),
+        background: cow_borrowed!("key(background)"),
         arrow_fill: cow_borrowed!("rgb(150, 150, 150)"),
         row_background: cow_borrowed!("transparent"),
         alternate_row_background: cow_borrowed!("rgb(50, 50, 50)"),
@@ -138,14 +158,14 @@ pub const DARK_THEME: Theme = Theme {
     sidebar: SidebarTheme {
         background: cow_borrowed!("rgb(20, 20, 20)"),
         font_theme: FontTheme {
-            color: cow_borrowed!("white"),
+            color: cow_borrowed!("key(text_primary)"),
         },
     },
     sidebar_item: SidebarItemTheme {
         background: cow_borrowed!("transparent"),
-        hover_background: cow_borrowed!("rgb(45, 45, 45)"),
+        hover_background: cow_borrowed!("key(surface_hover)"),
         font_theme: FontTheme {
-            color: cow_borrowed!("white"),
+            color: cow_borrowed!("key(text_primary)"),
         },
     },
     tile: TileTheme {
@@ -153,32 +173,32 @@ pub const DARK_THEME: Theme = Theme {
     },
     radio: RadioTheme {
         unselected_fill: cow_borrowed!("rgb(245, 245, 245)"),
-        selected_fill: cow_borrowed!("rgbrgb(103, 80, 164)"),
+        selected_fill: cow_borrowed!("rgb(103, 80, 164)"),
     },
     checkbox: CheckboxTheme {
         unselected_fill: cow_borrowed!("rgb(245, 245, 245)"),
         selected_fill: cow_borrowed!("rgb(103, 80, 164)"),
-        selected_icon_fill: cow_borrowed!("white"),
+        selected_icon_fill: cow_borrowed!("key(on_primary)"),
     },
     menu_item: MenuItemTheme {
-        hover_background: cow_borrowed!("rgb(45, 45, 45)"),
+        hover_background: cow_borrowed!("key(surface_hover)"),
         corner_radius: LIGHT_THEME.menu_item.corner_radius,
         font_theme: FontTheme {
-            color: cow_borrowed!("white"),
+            color: cow_borrowed!("key(text_primary)"),
         },
     },
     menu_container: MenuContainerTheme {
-        background: cow_borrowed!("rgb(35, 35, 35)"),
+        background: cow_borrowed!("key(surface)"),
         padding: LIGHT_THEME.menu_container.padding,
         shadow: LIGHT_THEME.menu_container.shadow,
     },
     snackbar: SnackBarTheme {
-        background: cow_borrowed!("rgb(35, 35, 35)"),
-        color: cow_borrowed!("white"),
+        background: cow_borrowed!("key(surface)"),
+        color: cow_borrowed!("key(text_primary)"),
     },
     popup: PopupTheme {
-        background: cow_borrowed!("rgb(25, 25, 25)"),
-        color: cow_borrowed!("white"),
+        background: cow_borrowed!("key(background)"),
+        color: cow_borrowed!("key(text_primary)"),
         cross_fill: cow_borrowed!("rgb(150, 150, 150)"),
         width: LIGHT_THEME.popup.width,
         height: LIGHT_THEME.popup.height,
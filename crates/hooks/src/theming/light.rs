@@ -2,54 +2,79 @@ use crate::cow_borrowed;
 use crate::theming::*;
 
 pub const LIGHT_THEME: Theme = Theme {
-    name: "light",
+    name: cow_borrowed!("light"),
+    tokens: DesignTokens {
+        primary: cow_borrowed!("rgb(103, 80, 164)"),
+        primary_hover: cow_borrowed!("rgb(91, 70, 144)"),
+        primary_pressed: cow_borrowed!("rgb(78, 61, 125)"),
+        on_primary: cow_borrowed!("white"),
+        secondary: cow_borrowed!("rgb(234, 221, 255)"),
+        secondary_hover: cow_borrowed!("rgb(206, 194, 224)"),
+        secondary_pressed: cow_borrowed!("rgb(178, 168, 194)"),
+        background: cow_borrowed!("white"),
+        surface: cow_borrowed!("rgb(245, 245, 245)"),
+        surface_hover: cow_borrowed!("rgb(235, 235, 235)"),
+        surface_pressed: cow_borrowed!("rgb(220, 220, 220)"),
+        border: cow_borrowed!("rgb(210, 210, 210)"),
+        focus_border: cow_borrowed!("rgb(180, 180, 180)"),
+        error: cow_borrowed!("rgb(179, 38, 30)"),
+        text_primary: cow_borrowed!("rgb(10, 10, 10)"),
+        text_secondary: cow_borrowed!("rgb(100, 100, 100)"),
+        text_disabled: cow_borrowed!("rgb(160, 160, 160)"),
+        radius_small: cow_borrowed!("6"),
+        radius_medium: cow_borrowed!("8"),
+        radius_large: cow_borrowed!("10"),
+        spacing_small: cow_borrowed!("4"),
+        spacing_medium: cow_borrowed!("8"),
+        spacing_large: cow_borrowed!("16"),
+    },
     body: BodyTheme {
         background: cow_borrowed!("white"),
         color: cow_borrowed!("black"),
         padding: cow_borrowed!("none"),
     },
     slider: SliderTheme {
-        background: cow_borrowed!("rgb(210, 210, 210)"),
-        thumb_background: cow_borrowed!("rgb(210, 210, 210)"),
-        thumb_inner_background: cow_borrowed!("rgb(103, 80, 164)"),
-        border_fill: cow_borrowed!("rgb(210, 210, 210)"),
+        background: cow_borrowed!("key(border)"),
+        thumb_background: cow_borrowed!("key(border)"),
+        thumb_inner_background: cow_borrowed!("key(primary)"),
+        border_fill: cow_borrowed!("key(border)"),
     },
     button: ButtonTheme {
-        background: cow_borrowed!("rgb(245, 245, 245)"),
-        hover_background: cow_borrowed!("rgb(235, 235, 235)"),
+        background: cow_borrowed!("key(surface)"),
+        hover_background: cow_borrowed!("key(surface_hover)"),
         font_theme: FontTheme {
-            color: cow_borrowed!("rgb(10, 10, 10)"),
+            color: cow_borrowed!("key(text_primary)"),
         },
-        border_fill: cow_borrowed!("rgb(210, 210, 210)"),
-        focus_border_fill: cow_borrowed!("rgb(180, 180, 180)"),
+        border_fill: cow_borrowed!("key(border)"),
+        focus_border_fill: cow_borrowed!("key(focus_border)"),
         shadow: cow_borrowed!("0 4 5 0 rgb(0, 0, 0, 0.1)"),
-        padding: cow_borrowed!("8 16"),
-        margin: cow_borrowed!("4"),
-        corner_radius: cow_borrowed!("8"),
+        padding: cow_borrowed!("key(spacing_medium) key(spacing_large)"),
+        margin: cow_borrowed!("key(spacing_small)"),
+        corner_radius: cow_borrowed!("key(radius_medium)"),
         width: cow_borrowed!("auto"),
         height: cow_borrowed!("auto"),
     },
     input: InputTheme {
-        background: cow_borrowed!("rgb(245, 245, 245)"),
-        hover_background: cow_borrowed!("rgb(235, 235, 235)"),
+        background: cow_borrowed!("key(surface)"),
+        hover_background: cow_borrowed!("key(surface_hover)"),
         font_theme: FontTheme {
-            color: cow_borrowed!("rgb(10, 10, 10)"),
+            color: cow_borrowed!("key(text_primary)"),
         },
         placeholder_font_theme: FontTheme {
-            color: cow_borrowed!("rgb(100, 100, 100)"),
+            color: cow_borrowed!("key(text_secondary)"),
         },
-        border_fill: cow_borrowed!("rgb(210, 210, 210)"),
+        border_fill: cow_borrowed!("key(border)"),
         width: cow_borrowed!("150"),
-        margin: cow_borrowed!("4"),
-        corner_radius: cow_borrowed!("10"),
+        margin: cow_borrowed!("key(spacing_small)"),
+        corner_radius: cow_borrowed!("key(radius_large)"),
     },
     switch: SwitchTheme {
         background: cow_borrowed!("rgb(121, 116, 126)"),
         thumb_background: cow_borrowed!("rgb(231, 224, 236)"),
-        enabled_background: cow_borrowed!("rgb(103, 80, 164)"),
-        enabled_thumb_background: cow_borrowed!("rgb(234, 221, 255)"),
-        focus_border_fill: cow_borrowed!("rgb(180, 180, 180)"),
-        enabled_focus_border_fill: cow_borrowed!("rgb(180, 180, 180)"),
+        enabled_background: cow_borrowed!("key(primary)"),
+        enabled_thumb_background: cow_borrowed!("key(secondary)"),
+        focus_border_fill: cow_borrowed!("key(focus_border)"),
+        enabled_focus_border_fill: cow_borrowed!("key(focus_border)"),
     },
     scroll_bar: ScrollBarTheme {
         background: cow_borrowed!("rgb(225, 225, 225)"),
@@ -64,32 +89,32 @@ pub const LIGHT_THEME: Theme = Theme {
         padding: cow_borrowed!("0"),
     },
     tooltip: TooltipTheme {
-        background: cow_borrowed!("rgb(245, 245, 245)"),
+        background: cow_borrowed!("key(surface)"),
         color: cow_borrowed!("rgb(25,25,25)"),
-        border_fill: cow_borrowed!("rgb(210, 210, 210)"),
+        border_fill: cow_borrowed!("key(border)"),
     },
     dropdown: DropdownTheme {
-        dropdown_background: cow_borrowed!("white"),
-        background_button: cow_borrowed!("rgb(245, 245, 245)"),
-        hover_background: cow_borrowed!("rgb(235, 235, 235)"),
+        dropdown_background: cow_borrowed!("key(background)"),
+        background_button: cow_borrowed!("key(surface)"),
+        hover_background: cow_borrowed!("key(surface_hover)"),
         font_theme: FontTheme {
-            color: cow_borrowed!("rgb(10, 10, 10)"),
+            color: cow_borrowed!("key(text_primary)"),
         },
-        border_fill: cow_borrowed!("rgb(210, 210, 210)"),
+        border_fill: cow_borrowed!("key(border)"),
         arrow_fill: cow_borrowed!("rgb(40, 40, 40)"),
     },
     dropdown_item: DropdownItemTheme {
-        background: cow_borrowed!("white"),
+        background: cow_borrowed!("key(background)"),
         select_background: cow_borrowed!("rgb(240, 240, 240)"),
-        hover_background: cow_borrowed!("rgb(220, 220, 220)"),
+        hover_background: cow_borrowed!("key(surface_pressed)"),
         font_theme: FontTheme {
-            color: cow_borrowed!("rgb(10, 10, 10)"),
+            color: cow_borrowed!("key(text_primary)"),
         },
     },
     accordion: AccordionTheme {
         color: cow_borrowed!("black"),
-        background: cow_borrowed!("rgb(245, 245, 245)"),
-        border_fill: cow_borrowed!("rgb(210, 210, 210)"),
+        background: cow_borrowed!("key(surface)"),
+        border_fill: cow_borrowed!("key(border)"),
     },
     loader: LoaderTheme {
         primary_color: cow_borrowed!("rgb(50, 50, 50)"),
@@ -98,9 +123,9 @@ pub const LIGHT_THEME: Theme = Theme {
         highlight_color: cow_borrowed!("rgb(43,106,208)"),
     },
     progress_bar: ProgressBarTheme {
-        color: cow_borrowed!("white"),
-        background: cow_borrowed!("rgb(210, 210, 210)"),
-        progress_background: cow_borrowed!("rgb(103, 80, 164)"),
+        color: cow_borrowed!("key(on_primary)"),
+        background: cow_borrowed!("key(border)"),
+        progress_background: cow_borrowed!("key(primary)"),
         width: cow_borrowed!("fill"),
         height: cow_borrowed!("20"),
     },
@@ -108,19 +133,19 @@ pub const LIGHT_THEME: Theme = Theme {
         font_theme: FontTheme {
             color: cow_borrowed!("black"),
         },
-        background: cow_borrowed!("white"),
+        background: cow_borrowed!("key(background)"),
         arrow_fill: cow_borrowed!("rgb(40, 40, 40)"),
         row_background: cow_borrowed!("transparent"),
         alternate_row_background: cow_borrowed!("rgb(240, 240, 240)"),
         divider_fill: cow_borrowed!("rgb(200, 200, 200)"),
         height: cow_borrowed!("auto"),
-        corner_radius: cow_borrowed!("6"),
+        corner_radius: cow_borrowed!("key(radius_small)"),
         shadow: cow_borrowed!("0 2 15 5 rgb(35, 35, 35, 70)"),
     },
     canvas: CanvasTheme {
         width: cow_borrowed!("300"),
         height: cow_borrowed!("150"),
-        background: cow_borrowed!("white"),
+        background: cow_borrowed!("key(background)"),
     },
     graph: GraphTheme {
         width: cow_borrowed!("100%"),
@@ -136,16 +161,16 @@ pub const LIGHT_THEME: Theme = Theme {
         margin: cow_borrowed!("none"),
     },
     sidebar: SidebarTheme {
-        background: cow_borrowed!("rgb(245, 245, 245)"),
+        background: cow_borrowed!("key(surface)"),
         font_theme: FontTheme {
-            color: cow_borrowed!("rgb(10, 10, 10)"),
+            color: cow_borrowed!("key(text_primary)"),
         },
     },
     sidebar_item: SidebarItemTheme {
         background: cow_borrowed!("transparent"),
         hover_background: cow_borrowed!("rgb(230, 230, 230)"),
         font_theme: FontTheme {
-            color: cow_borrowed!("rgb(10, 10, 10)"),
+            color: cow_borrowed!("key(text_primary)"),
         },
     },
     tile: TileTheme {
@@ -153,31 +178,31 @@ pub const LIGHT_THEME: Theme = Theme {
     },
     radio: RadioTheme {
         unselected_fill: cow_borrowed!("rgb(35, 35, 35)"),
-        selected_fill: cow_borrowed!("rgb(103, 80, 164)"),
+        selected_fill: cow_borrowed!("key(primary)"),
     },
     checkbox: CheckboxTheme {
         unselected_fill: cow_borrowed!("rgb(80, 80, 80)"),
-        selected_fill: cow_borrowed!("rgb(103, 80, 164)"),
-        selected_icon_fill: cow_borrowed!("white"),
+        selected_fill: cow_borrowed!("key(primary)"),
+        selected_icon_fill: cow_borrowed!("key(on_primary)"),
     },
     menu_item: MenuItemTheme {
-        hover_background: cow_borrowed!("rgb(235, 235, 235)"),
-        corner_radius: cow_borrowed!("8"),
+        hover_background: cow_borrowed!("key(surface_hover)"),
+        corner_radius: cow_borrowed!("key(radius_medium)"),
         font_theme: FontTheme {
-            color: cow_borrowed!("rgb(10, 10, 10)"),
+            color: cow_borrowed!("key(text_primary)"),
         },
     },
     menu_container: MenuContainerTheme {
-        background: cow_borrowed!("rgb(245, 245, 245)"),
-        padding: cow_borrowed!("4"),
+        background: cow_borrowed!("key(surface)"),
+        padding: cow_borrowed!("key(spacing_small)"),
         shadow: cow_borrowed!("0 2 5 2 rgb(0, 0, 0, 0.1)"),
     },
     snackbar: SnackBarTheme {
-        background: cow_borrowed!("rgb(235, 235, 235)"),
-        color: cow_borrowed!("rgb(103, 80, 164)"),
+        background: cow_borrowed!("key(surface_hover)"),
+        color: cow_borrowed!("key(primary)"),
     },
     popup: PopupTheme {
-        background: cow_borrowed!("white"),
+        background: cow_borrowed!("key(background)"),
         color: cow_borrowed!("black"),
         cross_fill: cow_borrowed!("rgb(40, 40, 40)"),
         width: cow_borrowed!("350"),
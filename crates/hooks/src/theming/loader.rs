@@ -0,0 +1,111 @@
+use std::error::Error;
+use std::fmt;
+use std::path::Path;
+
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::Theme;
+
+/// Error returned when a [`Theme`] can't be loaded.
+#[derive(Debug)]
+pub enum LoadThemeError {
+    /// The theme file couldn't be read.
+    Io(std::io::Error),
+    /// The theme isn't valid TOML.
+    Toml(toml::de::Error),
+    /// The theme isn't valid JSON, or its values don't match the [`Theme`] fields.
+    Json(serde_json::Error),
+    /// The theme file doesn't have a `.toml` or `.json` extension.
+    UnsupportedFormat,
+}
+
+impl fmt::Display for LoadThemeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "Failed to read the theme file: {err}"),
+            Self::Toml(err) => write!(f, "Invalid TOML theme: {err}"),
+            Self::Json(err) => write!(f, "Invalid JSON theme: {err}"),
+            Self::UnsupportedFormat => {
+                write!(f, "Unsupported theme file, expected a .toml or .json file")
+            }
+        }
+    }
+}
+
+impl Error for LoadThemeError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Io(err) => Some(err),
+            Self::Toml(err) => Some(err),
+            Self::Json(err) => Some(err),
+            Self::UnsupportedFormat => None,
+        }
+    }
+}
+
+impl Theme {
+    /// Build a [`Theme`] from a TOML document, using the values of `base` for the missing keys.
+    ///
+    /// ```rust
+    /// # use freya_hooks::{Theme, DARK_THEME};
+    /// let theme = Theme::from_toml(
+    ///     r#"
+    ///     name = "custom"
+    ///
+    ///     [button]
+    ///     background = "rgb(255, 95, 0)"
+    ///     "#,
+    ///     &DARK_THEME,
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_eq!(theme.button.background, "rgb(255, 95, 0)");
+    /// assert_eq!(theme.button.hover_background, DARK_THEME.button.hover_background);
+    /// ```
+    pub fn from_toml(source: &str, base: &Theme) -> Result<Self, LoadThemeError> {
+        let overrides = toml::from_str(source).map_err(LoadThemeError::Toml)?;
+        Self::from_value(overrides, base)
+    }
+
+    /// Build a [`Theme`] from a JSON document, using the values of `base` for the missing keys.
+    pub fn from_json(source: &str, base: &Theme) -> Result<Self, LoadThemeError> {
+        let overrides = serde_json::from_str(source).map_err(LoadThemeError::Json)?;
+        Self::from_value(overrides, base)
+    }
+
+    /// Load a [`Theme`] from a `.toml` or `.json` file, using the values of `base` for the missing keys.
+    pub fn from_file(path: impl AsRef<Path>, base: &Theme) -> Result<Self, LoadThemeError> {
+        let path = path.as_ref();
+        let from_source = match path.extension().and_then(|extension| extension.to_str()) {
+            Some("toml") => Self::from_toml,
+            Some("json") => Self::from_json,
+            _ => return Err(LoadThemeError::UnsupportedFormat),
+        };
+        let source = std::fs::read_to_string(path).map_err(LoadThemeError::Io)?;
+        from_source(&source, base)
+    }
+
+    fn from_value(overrides: Value, base: &Theme) -> Result<Self, LoadThemeError> {
+        let mut theme = serde_json::to_value(base).map_err(LoadThemeError::Json)?;
+        merge_values(&mut theme, overrides);
+        Theme::deserialize(theme).map_err(LoadThemeError::Json)
+    }
+}
+
+/// Recursively overwrite the values of `base` with the ones in `overrides`.
+fn merge_values(base: &mut Value, overrides: Value) {
+    match (base, overrides) {
+        (Value::Object(base), Value::Object(overrides)) => {
+            for (key, value) in overrides {
+                match base.get_mut(&key) {
+                    Some(base_value) => merge_values(base_value, value),
+                    None => {
+                        base.insert(key, value);
+                    }
+                }
+            }
+        }
+        (base, overrides) => *base = overrides,
+    }
+}
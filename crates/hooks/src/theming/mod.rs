@@ -1,14 +1,22 @@
 mod dark;
 mod light;
+mod loader;
+mod tokens;
 
 pub use dark::*;
 pub use light::*;
+pub use loader::*;
+pub use tokens::*;
+
+use serde::{Deserialize, Serialize};
 
 #[doc(hidden)]
 pub use ::core::default::Default;
 #[doc(hidden)]
 pub use ::paste::paste;
 #[doc(hidden)]
+pub use ::serde;
+#[doc(hidden)]
 pub use ::std::borrow::Cow;
 
 /// Alias for `Cow::Borrowed`, because that's used a million times so shortening it is nice.
@@ -20,13 +28,17 @@ macro_rules! cow_borrowed {
     };
 }
 
+/// Both the theme and its `ThemeWith` variant can be (de)serialized with `serde`,
+/// so owned fields must implement `Serialize` and `Deserialize` as well, and the crate calling the macro must depend on `serde`.
+/// Borrowed fields are skipped, so their types must implement `Default`.
+///
 /// Example usage:
 ///
 /// ```rust
 /// # use crate::freya_hooks::define_theme;
 /// # use crate::freya_hooks::FontTheme;
 /// # use crate::freya_hooks::FontThemeWith;
-/// # #[derive(Clone, Debug, PartialEq, Eq)]
+/// # #[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 /// # struct Bar;
 /// # #[derive(Clone, Debug, PartialEq, Eq)]
 /// # struct Foo;
@@ -36,7 +48,7 @@ macro_rules! cow_borrowed {
 ///         %[cows]
 ///         cow_string: str,
 ///         %[borrowed]
-///         borrowed_data: &'a Foo,
+///         borrowed_data: &'a [Foo],
 ///         %[owned]
 ///         owned_data: Bar,
 ///         %[subthemes]
@@ -87,12 +99,13 @@ macro_rules! define_theme {
         $crate::define_theme!(NOTHING=$($($owned_attr_control)?)?);
         $crate::define_theme!(NOTHING=$($($subthemes_attr_control)?)?);
         $crate::paste! {
-            #[derive(Default, Clone, Debug, PartialEq, Eq)]
+            #[derive(Default, Clone, Debug, PartialEq, Eq, $crate::serde::Serialize, $crate::serde::Deserialize)]
             #[doc = "You can use this to change a theme for only one component, with the `theme` property."]
             $(#[$attrs])*
             $vis struct [<$name ThemeWith>] $(<$lifetime>)? {
                 $($(
                     $(#[$borrowed_field_attrs])*
+                    #[serde(skip)]
                     pub $borrowed_field_name: Option<$borrowed_field_ty>,
                 )*)?
                 $($(
@@ -109,12 +122,13 @@ macro_rules! define_theme {
                 )*)?
             }
 
-            #[derive(Clone, Debug, PartialEq, Eq)]
+            #[derive(Clone, Debug, PartialEq, Eq, $crate::serde::Serialize, $crate::serde::Deserialize)]
             $(#[doc = "Theming properties for the `" $name "` component."] $($component_attr_control)?)?
             $(#[$attrs])*
             $vis struct [<$name Theme>] $(<$lifetime>)? {
                 $($(
                     $(#[$borrowed_field_attrs])*
+                    #[serde(skip)]
                     pub $borrowed_field_name: $borrowed_field_ty,
                 )*)?
                 $($(
@@ -158,6 +172,19 @@ macro_rules! define_theme {
                         }
                     )*)?
                 }
+
+                #[doc = "Replaces the `key(token_name)` references of each field with the values of the given `DesignTokens`."]
+                pub fn resolve(&mut self, tokens: &$crate::DesignTokens) {
+                    $($(
+                        self.$subtheme_field_name.resolve(tokens);
+                    )*)?
+
+                    $($(
+                        if let Some($cow_field_name) = tokens.resolve(&self.$cow_field_name) {
+                            self.$cow_field_name = $crate::Cow::Owned($cow_field_name);
+                        }
+                    )*)?
+                }
             }
         }
     };
@@ -540,9 +567,12 @@ define_theme! {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Theme {
-    pub name: &'static str,
+    /// Name of the theme, a [`Cow`] so themes loaded at runtime with [`Theme::from_file`] can have their own name.
+    pub name: Cow<'static, str>,
+    /// Values referenced by the component themes with `key(token_name)`.
+    pub tokens: DesignTokens,
     pub body: BodyTheme,
     pub button: ButtonTheme,
     pub switch: SwitchTheme,
@@ -573,6 +603,47 @@ pub struct Theme {
     pub popup: PopupTheme,
 }
 
+impl Theme {
+    /// Replace the `key(token_name)` references of the component themes with the values of [`Theme::tokens`].
+    pub fn resolve(&mut self) {
+        let tokens = &self.tokens;
+        self.body.resolve(tokens);
+        self.button.resolve(tokens);
+        self.switch.resolve(tokens);
+        self.scroll_bar.resolve(tokens);
+        self.scroll_view.resolve(tokens);
+        self.slider.resolve(tokens);
+        self.tooltip.resolve(tokens);
+        self.dropdown.resolve(tokens);
+        self.dropdown_item.resolve(tokens);
+        self.accordion.resolve(tokens);
+        self.loader.resolve(tokens);
+        self.link.resolve(tokens);
+        self.progress_bar.resolve(tokens);
+        self.table.resolve(tokens);
+        self.input.resolve(tokens);
+        self.canvas.resolve(tokens);
+        self.graph.resolve(tokens);
+        self.network_image.resolve(tokens);
+        self.icon.resolve(tokens);
+        self.sidebar.resolve(tokens);
+        self.sidebar_item.resolve(tokens);
+        self.tile.resolve(tokens);
+        self.radio.resolve(tokens);
+        self.checkbox.resolve(tokens);
+        self.menu_item.resolve(tokens);
+        self.menu_container.resolve(tokens);
+        self.snackbar.resolve(tokens);
+        self.popup.resolve(tokens);
+    }
+
+    /// Same as [`Theme::resolve`] but returning the resolved [`Theme`].
+    pub fn resolved(mut self) -> Self {
+        self.resolve();
+        self
+    }
+}
+
 impl Default for Theme {
     fn default() -> Self {
         LIGHT_THEME
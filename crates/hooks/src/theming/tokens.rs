@@ -0,0 +1,201 @@
+use std::borrow::Cow;
+
+use freya_engine::prelude::Color;
+use freya_node_state::{Parse, ParseColorError};
+use serde::{Deserialize, Serialize};
+
+use crate::{Theme, DARK_THEME, LIGHT_THEME};
+
+macro_rules! define_tokens {
+    (
+        $(
+            $(#[$attrs:meta])*
+            $name:ident,
+        )*
+    ) => {
+        /// Design tokens shared by all the component themes.
+        ///
+        /// Theme values reference them with `key(token_name)`, e.g. `key(primary)` or `key(spacing_small) key(spacing_large)`,
+        /// so rebranding an app only means changing the tokens.
+        #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+        pub struct DesignTokens {
+            $(
+                $(#[$attrs])*
+                pub $name: Cow<'static, str>,
+            )*
+        }
+
+        impl DesignTokens {
+            /// Get the value of a token by its name.
+            pub fn get(&self, name: &str) -> Option<&str> {
+                match name {
+                    $(
+                        stringify!($name) => Some(&self.$name),
+                    )*
+                    _ => None,
+                }
+            }
+        }
+    };
+}
+
+define_tokens! {
+    /// Main color of the app, used by selected or enabled components.
+    primary,
+    primary_hover,
+    primary_pressed,
+    /// Color for content displayed on top of the primary color.
+    on_primary,
+    /// Accent color, used to complement the primary color.
+    secondary,
+    secondary_hover,
+    secondary_pressed,
+    /// Background of the app.
+    background,
+    /// Background of components, like buttons or inputs.
+    surface,
+    surface_hover,
+    surface_pressed,
+    border,
+    focus_border,
+    error,
+    /// Color of the main text.
+    text_primary,
+    /// Color of less relevant text, like placeholders.
+    text_secondary,
+    /// Color of the text of disabled components.
+    text_disabled,
+    radius_small,
+    radius_medium,
+    radius_large,
+    spacing_small,
+    spacing_medium,
+    spacing_large,
+}
+
+impl DesignTokens {
+    /// Replace the `key(token_name)` references of the given value with the value of the tokens.
+    /// Returns `None` if the value has no references to known tokens.
+    pub fn resolve(&self, value: &str) -> Option<String> {
+        let mut resolved = String::new();
+        let mut rest = value;
+        let mut found = false;
+
+        while let Some(start) = rest.find("key(") {
+            let name_start = start + "key(".len();
+            let Some(name_len) = rest[name_start..].find(')') else {
+                break;
+            };
+            let name = &rest[name_start..name_start + name_len];
+            resolved.push_str(&rest[..start]);
+            match self.get(name) {
+                Some(token) => {
+                    resolved.push_str(token);
+                    found = true;
+                }
+                None => resolved.push_str(&rest[start..name_start + name_len + 1]),
+            }
+            rest = &rest[name_start + name_len + 1..];
+        }
+
+        if found {
+            resolved.push_str(rest);
+            Some(resolved)
+        } else {
+            None
+        }
+    }
+}
+
+/// Seed colors used to derive a whole [`Theme`], see [`Theme::from_seeds`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ThemeSeeds<'a> {
+    pub primary: &'a str,
+    pub secondary: &'a str,
+    /// Background of the app, a dark one derives a dark theme.
+    pub background: &'a str,
+    pub error: &'a str,
+}
+
+impl DesignTokens {
+    /// Derive the color tokens from the given seeds, including the hover and pressed shades.
+    /// The rest of tokens, like the spacing ones, are taken from `base`.
+    pub fn from_seeds(seeds: ThemeSeeds, base: &DesignTokens) -> Result<Self, ParseColorError> {
+        let primary = Color::parse(seeds.primary)?;
+        let secondary = Color::parse(seeds.secondary)?;
+        let background = Color::parse(seeds.background)?;
+        let error = Color::parse(seeds.error)?;
+
+        let contrast = if is_dark(background) {
+            Color::WHITE
+        } else {
+            Color::BLACK
+        };
+        let on = |color: Color| {
+            if is_dark(color) {
+                Color::WHITE
+            } else {
+                Color::BLACK
+            }
+        };
+        let shade = |color: Color, amount: f32| to_rgb(mix(color, contrast, amount));
+
+        Ok(Self {
+            primary: to_rgb(primary),
+            primary_hover: shade(primary, 0.12),
+            primary_pressed: shade(primary, 0.24),
+            on_primary: to_rgb(on(primary)),
+            secondary: to_rgb(secondary),
+            secondary_hover: shade(secondary, 0.12),
+            secondary_pressed: shade(secondary, 0.24),
+            background: to_rgb(background),
+            surface: shade(background, 0.04),
+            surface_hover: shade(background, 0.08),
+            surface_pressed: shade(background, 0.14),
+            border: shade(background, 0.18),
+            focus_border: shade(background, 0.3),
+            error: to_rgb(error),
+            text_primary: shade(background, 0.96),
+            text_secondary: shade(background, 0.6),
+            text_disabled: shade(background, 0.38),
+            ..base.clone()
+        })
+    }
+}
+
+impl Theme {
+    /// Derive a whole [`Theme`] from a few seed colors.
+    /// Components are themed like in [`LIGHT_THEME`], or [`DARK_THEME`] if the background is dark.
+    pub fn from_seeds(seeds: ThemeSeeds) -> Result<Self, ParseColorError> {
+        let base = if is_dark(Color::parse(seeds.background)?) {
+            DARK_THEME
+        } else {
+            LIGHT_THEME
+        };
+        Ok(Self {
+            tokens: DesignTokens::from_seeds(seeds, &base.tokens)?,
+            ..base
+        })
+    }
+}
+
+fn is_dark(color: Color) -> bool {
+    let luminance =
+        0.2126 * color.r() as f32 + 0.7152 * color.g() as f32 + 0.0722 * color.b() as f32;
+    luminance < 128.0
+}
+
+/// Blend `from` into `to` by the given `amount`, from `0.0` to `1.0`.
+fn mix(from: Color, to: Color, amount: f32) -> Color {
+    let channel =
+        |from: u8, to: u8| (from as f32 + (to as f32 - from as f32) * amount).round() as u8;
+    Color::from_rgb(
+        channel(from.r(), to.r()),
+        channel(from.g(), to.g()),
+        channel(from.b(), to.b()),
+    )
+}
+
+fn to_rgb(color: Color) -> Cow<'static, str> {
+    Cow::Owned(format!("rgb({}, {}, {})", color.r(), color.g(), color.b()))
+}
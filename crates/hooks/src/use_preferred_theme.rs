@@ -0,0 +1,45 @@
+use dioxus_core::prelude::{spawn, try_consume_context, use_hook};
+use dioxus_signals::{ReadOnlySignal, Readable, Signal, Writable};
+use freya_core::types::PreferredThemeReceiver;
+
+pub use freya_common::PreferredTheme;
+
+/// Subscribe to the light/dark preference of the platform.
+///
+/// Platforms that don't report a preference, like most Linux desktops, always stay in [`PreferredTheme::Light`].
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let is_dark = use_preferred_theme().read().is_dark();
+///
+///     rsx!(
+///         label {
+///             "Dark mode: {is_dark}"
+///         }
+///     )
+/// }
+/// ```
+pub fn use_preferred_theme() -> ReadOnlySignal<PreferredTheme> {
+    use_hook(|| {
+        let receiver = try_consume_context::<PreferredThemeReceiver>();
+        let mut signal = Signal::new(
+            receiver
+                .as_ref()
+                .map(|receiver| *receiver.borrow())
+                .unwrap_or_default(),
+        );
+
+        if let Some(mut receiver) = receiver {
+            spawn(async move {
+                while receiver.changed().await.is_ok() {
+                    if *signal.peek() != *receiver.borrow() {
+                        signal.set(*receiver.borrow());
+                    }
+                }
+            });
+        }
+
+        signal.into()
+    })
+}
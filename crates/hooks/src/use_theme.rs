@@ -1,30 +1,117 @@
+use std::{path::PathBuf, sync::OnceLock, time::Duration};
+
 use crate::theming::*;
-use dioxus_core::prelude::try_consume_context;
-use dioxus_hooks::{use_context, use_context_provider};
-use dioxus_signals::{Readable, Signal};
+use dioxus_core::prelude::{spawn, try_consume_context};
+use dioxus_core::use_hook;
+use dioxus_hooks::{use_context, use_context_provider, use_memo};
+use dioxus_signals::{Memo, Readable, Signal, Writable};
+use tracing::error;
+
+/// How often the theme file of [`use_init_theme_from_file`] is checked for changes.
+const THEME_FILE_POLL_INTERVAL: Duration = Duration::from_millis(500);
 
 /// Provide a custom [`Theme`].
-pub fn use_init_theme(theme: Theme) {
-    use_context_provider(|| Signal::new(theme));
+pub fn use_init_theme(theme: Theme) -> Signal<Theme> {
+    let theme = use_context_provider(|| Signal::new(theme));
+    use_provide_resolved_theme(theme);
+    theme
+}
+
+/// Provide the resolved version of the given [`Theme`] signal to [`use_get_theme`],
+/// so its `key(token_name)` references are resolved once per change and not once per read.
+fn use_provide_resolved_theme(theme: Signal<Theme>) {
+    let resolved_theme = use_memo(move || theme.read().clone().resolved());
+    use_context_provider(|| resolved_theme);
+}
+
+/// Provide a [`Theme`] loaded from a `.toml` or `.json` file, see [`Theme::from_file`].
+/// Missing keys are taken from `base`, which is also used as a whole if the file can't be loaded.
+///
+/// The theme is reloaded every time the file changes, so colors can be tweaked without rebuilding the app.
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     use_init_theme_from_file("theme.toml", DARK_THEME);
+///
+///     rsx!(
+///         Button {
+///             label {
+///                 "Themed from a file"
+///             }
+///         }
+///     )
+/// }
+/// ```
+pub fn use_init_theme_from_file(path: impl Into<PathBuf>, base: Theme) -> Signal<Theme> {
+    let path = use_hook(|| path.into());
+
+    let load_theme = {
+        let path = path.clone();
+        move || {
+            Theme::from_file(&path, &base).unwrap_or_else(|err| {
+                error!("Failed to load the theme from {path:?}: {err:?}");
+                base.clone()
+            })
+        }
+    };
+
+    let mut theme = use_context_provider(|| Signal::new(load_theme()));
+    use_provide_resolved_theme(theme);
+
+    use_hook(move || {
+        spawn(async move {
+            let modified_time = || {
+                std::fs::metadata(&path)
+                    .and_then(|metadata| metadata.modified())
+                    .ok()
+            };
+            let mut last_modified_time = modified_time();
+            let mut interval = tokio::time::interval(THEME_FILE_POLL_INTERVAL);
+            loop {
+                interval.tick().await;
+                let modified_time = modified_time();
+                if modified_time != last_modified_time {
+                    last_modified_time = modified_time;
+                    theme.set(load_theme());
+                }
+            }
+        });
+    });
+
+    theme
 }
 
 /// Provide the default [`Theme`].
 pub fn use_init_default_theme() {
-    use_context_provider(|| Signal::new(Theme::default()));
+    use_init_theme(Theme::default());
 }
 
 /// Subscribe to [`Theme`] changes.
+/// The `key(token_name)` references of the [`Theme`] are not resolved, so its tokens can be changed at runtime.
 pub fn use_theme() -> Signal<Theme> {
     use_context::<Signal<Theme>>()
 }
 
 /// Subscribe to [`Theme`] changes, default theme will be used if there is no provided [`Theme`].
+/// The `key(token_name)` references of the returned [`Theme`] are already resolved.
 ///
 /// Primarily used by built-in components that have no control of whether they will inherit a [`Theme`] or not.
 pub fn use_get_theme() -> Theme {
+    static DEFAULT_THEME: OnceLock<Theme> = OnceLock::new();
+
+    if let Some(theme) = try_consume_context::<Memo<Theme>>() {
+        return theme.read().clone();
+    }
+
+    // Themes provided without the `use_init_theme` hooks
     try_consume_context::<Signal<Theme>>()
-        .map(|v| v.read().clone())
-        .unwrap_or_default()
+        .map(|v| v.read().clone().resolved())
+        .unwrap_or_else(|| {
+            DEFAULT_THEME
+                .get_or_init(|| Theme::default().resolved())
+                .clone()
+        })
 }
 
 /// This macro has three arguments separator by commas.
@@ -57,10 +144,13 @@ pub fn use_get_theme() -> Theme {
 #[macro_export]
 macro_rules! use_applied_theme {
     ($theme_prop:expr, $theme_name:ident) => {{
-        let mut theme = ::freya_hooks::use_get_theme().$theme_name;
+        let full_theme = ::freya_hooks::use_get_theme();
+        let mut theme = full_theme.$theme_name;
 
         if let Some(theme_override) = $theme_prop {
             theme.apply_optional(theme_override);
+            // Overrides can reference the design tokens too
+            theme.resolve(&full_theme.tokens);
         }
 
         theme
@@ -0,0 +1,131 @@
+use freya_engine::prelude::Color;
+use freya_hooks::{
+    ButtonTheme, ButtonThemeWith, DesignTokens, Theme, ThemeSeeds, DARK_THEME, LIGHT_THEME,
+};
+use freya_node_state::Parse;
+use serde_json::Value;
+
+#[test]
+pub fn resolve_tokens() {
+    let tokens = &LIGHT_THEME.tokens;
+
+    assert_eq!(
+        tokens.resolve("key(primary)").as_deref(),
+        Some("rgb(103, 80, 164)")
+    );
+    assert_eq!(
+        tokens
+            .resolve("key(spacing_medium) key(spacing_large)")
+            .as_deref(),
+        Some("8 16")
+    );
+    assert_eq!(
+        tokens.resolve("0 4 5 0 key(border)").as_deref(),
+        Some("0 4 5 0 rgb(210, 210, 210)")
+    );
+
+    // Values without known tokens are left as they are
+    assert_eq!(tokens.resolve("rgb(0, 0, 0)"), None);
+    assert_eq!(tokens.resolve("key(unknown)"), None);
+}
+
+#[test]
+pub fn resolve_theme() {
+    let theme = DARK_THEME.resolved();
+
+    assert_eq!(theme.button.background, "rgb(35, 35, 35)");
+    assert_eq!(theme.button.font_theme.color, "white");
+    assert_eq!(theme.button.padding, "8 16");
+    // Values that don't reference tokens are kept
+    assert_eq!(theme.checkbox.selected_fill, "rgb(103, 80, 164)");
+
+    let mut button = ButtonTheme {
+        background: "key(primary)".into(),
+        ..theme.button.clone()
+    };
+    button.apply_optional(&ButtonThemeWith {
+        border_fill: Some("key(error)".into()),
+        ..Default::default()
+    });
+    button.resolve(&theme.tokens);
+
+    assert_eq!(button.background, "rgb(255, 95, 0)");
+    assert_eq!(button.border_fill, "rgb(242, 184, 181)");
+}
+
+#[test]
+pub fn resolved_theme_colors() {
+    fn assert_colors(value: &Value) {
+        let Value::Object(fields) = value else {
+            return;
+        };
+        for (name, value) in fields {
+            match value {
+                Value::String(color)
+                    if name.contains("background")
+                        || name.ends_with("fill")
+                        || name.ends_with("color") =>
+                {
+                    assert!(Color::parse(color).is_ok(), "{name}: {color}")
+                }
+                value => assert_colors(value),
+            }
+        }
+    }
+
+    for theme in [LIGHT_THEME, DARK_THEME] {
+        assert_colors(&serde_json::to_value(theme.resolved()).unwrap());
+    }
+}
+
+#[test]
+pub fn theme_from_seeds() {
+    let seeds = ThemeSeeds {
+        primary: "rgb(0, 100, 200)",
+        secondary: "rgb(200, 100, 0)",
+        background: "white",
+        error: "rgb(200, 0, 0)",
+    };
+    let theme = Theme::from_seeds(seeds).unwrap();
+
+    assert_eq!(theme.name, LIGHT_THEME.name);
+    assert_eq!(
+        theme.tokens,
+        DesignTokens {
+            primary: "rgb(0, 100, 200)".into(),
+            primary_hover: "rgb(0, 88, 176)".into(),
+            primary_pressed: "rgb(0, 76, 152)".into(),
+            on_primary: "rgb(255, 255, 255)".into(),
+            secondary: "rgb(200, 100, 0)".into(),
+            secondary_hover: "rgb(176, 88, 0)".into(),
+            secondary_pressed: "rgb(152, 76, 0)".into(),
+            background: "rgb(255, 255, 255)".into(),
+            surface: "rgb(245, 245, 245)".into(),
+            surface_hover: "rgb(235, 235, 235)".into(),
+            surface_pressed: "rgb(219, 219, 219)".into(),
+            border: "rgb(209, 209, 209)".into(),
+            focus_border: "rgb(179, 179, 179)".into(),
+            error: "rgb(200, 0, 0)".into(),
+            text_primary: "rgb(10, 10, 10)".into(),
+            text_secondary: "rgb(102, 102, 102)".into(),
+            text_disabled: "rgb(158, 158, 158)".into(),
+            ..LIGHT_THEME.tokens
+        }
+    );
+
+    // Dark backgrounds derive dark themes
+    let theme = Theme::from_seeds(ThemeSeeds {
+        background: "rgb(20, 20, 20)",
+        ..seeds
+    })
+    .unwrap();
+    assert_eq!(theme.name, DARK_THEME.name);
+    assert_eq!(theme.tokens.surface, "rgb(29, 29, 29)");
+    assert_eq!(theme.tokens.text_primary, "rgb(246, 246, 246)");
+
+    assert!(Theme::from_seeds(ThemeSeeds {
+        primary: "not a color",
+        ..seeds
+    })
+    .is_err());
+}
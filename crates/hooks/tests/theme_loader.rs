@@ -0,0 +1,66 @@
+use freya_hooks::{LoadThemeError, Theme, DARK_THEME, LIGHT_THEME};
+
+#[test]
+pub fn theme_from_toml() {
+    let theme = Theme::from_toml(
+        r#"
+        name = "custom"
+
+        [button]
+        background = "blue"
+
+        [button.font_theme]
+        color = "white"
+        "#,
+        &LIGHT_THEME,
+    )
+    .unwrap();
+
+    assert_eq!(theme.name, "custom");
+    assert_eq!(theme.button.background, "blue");
+    assert_eq!(theme.button.font_theme.color, "white");
+
+    // Missing keys come from the base theme
+    assert_eq!(
+        theme.button.hover_background,
+        LIGHT_THEME.button.hover_background
+    );
+    assert_eq!(theme.body, LIGHT_THEME.body);
+}
+
+#[test]
+pub fn theme_from_json() {
+    let theme = Theme::from_json(r#"{ "body": { "background": "black" } }"#, &DARK_THEME).unwrap();
+
+    assert_eq!(theme.name, DARK_THEME.name);
+    assert_eq!(theme.body.background, "black");
+    assert_eq!(theme.body.color, DARK_THEME.body.color);
+    assert_eq!(theme.input, DARK_THEME.input);
+}
+
+#[test]
+pub fn theme_round_trip() {
+    let source = toml::to_string(&DARK_THEME).unwrap();
+
+    assert_eq!(Theme::from_toml(&source, &LIGHT_THEME).unwrap(), DARK_THEME);
+}
+
+#[test]
+pub fn invalid_theme() {
+    assert!(matches!(
+        Theme::from_toml("[button]\nbackground = 5", &LIGHT_THEME),
+        Err(LoadThemeError::Json(_))
+    ));
+    assert!(matches!(
+        Theme::from_toml("button = ", &LIGHT_THEME),
+        Err(LoadThemeError::Toml(_))
+    ));
+    assert!(matches!(
+        Theme::from_file("theme.yaml", &LIGHT_THEME),
+        Err(LoadThemeError::UnsupportedFormat)
+    ));
+    assert!(matches!(
+        Theme::from_file("missing-theme.toml", &LIGHT_THEME),
+        Err(LoadThemeError::Io(_))
+    ));
+}
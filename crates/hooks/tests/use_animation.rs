@@ -210,3 +210,43 @@ pub async fn auto_start() {
     let width = utils.root().get(0).area().unwrap().width();
     assert_eq!(width, 100.0);
 }
+
+#[tokio::test]
+pub async fn virtual_clock() {
+    fn use_animation_app() -> Element {
+        let animation = use_animation(|ctx| {
+            ctx.auto_start(true);
+            ctx.with(AnimNum::new(0., 100.).time(100).function(Function::Linear))
+        });
+
+        let progress = animation.get().read().as_f32();
+
+        rsx!(rect {
+            width: "{progress}",
+        })
+    }
+
+    let mut utils = launch_test_with_config(
+        use_animation_app,
+        TestingConfig {
+            virtual_clock: true,
+            ..TestingConfig::default()
+        },
+    );
+
+    // Initial state, no time has passed yet
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+    assert_eq!(utils.root().get(0).area().unwrap().width(), 0.0);
+
+    // Frame by frame
+    utils.advance_time(Duration::from_millis(25)).await;
+    assert_eq!(utils.root().get(0).area().unwrap().width(), 25.0);
+
+    utils.advance_time(Duration::from_millis(25)).await;
+    assert_eq!(utils.root().get(0).area().unwrap().width(), 50.0);
+
+    // Already finished
+    utils.advance_time(Duration::from_millis(60)).await;
+    assert_eq!(utils.root().get(0).area().unwrap().width(), 100.0);
+}
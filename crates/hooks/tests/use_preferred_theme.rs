@@ -0,0 +1,53 @@
+use dioxus::prelude::*;
+use freya_common::EventMessage;
+use freya_hooks::{use_platform, use_preferred_theme};
+use freya_testing::prelude::*;
+
+#[tokio::test]
+async fn preferred_theme() {
+    fn preferred_theme_app() -> Element {
+        let preferred_theme = *use_preferred_theme().read();
+
+        rsx!(label { "{preferred_theme:?}" })
+    }
+
+    let mut utils = launch_test_with_config(
+        preferred_theme_app,
+        TestingConfig {
+            preferred_theme: PreferredTheme::Dark,
+            ..TestingConfig::default()
+        },
+    );
+
+    utils.wait_for_update().await;
+
+    assert_eq!(utils.root().get(0).get(0).text(), Some("Dark"));
+
+    utils.set_preferred_theme(PreferredTheme::Light);
+    utils.wait_for_update().await;
+
+    assert_eq!(utils.root().get(0).get(0).text(), Some("Light"));
+}
+
+#[tokio::test]
+async fn preferred_theme_changed_event() {
+    fn preferred_theme_app() -> Element {
+        let platform = use_platform();
+        let preferred_theme = *use_preferred_theme().read();
+
+        use_hook(move || {
+            platform
+                .send(EventMessage::PreferredThemeChanged(PreferredTheme::Dark))
+                .unwrap();
+        });
+
+        rsx!(label { "{preferred_theme:?}" })
+    }
+
+    let mut utils = launch_test(preferred_theme_app);
+
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+
+    assert_eq!(utils.root().get(0).get(0).text(), Some("Dark"));
+}
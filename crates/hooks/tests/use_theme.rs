@@ -0,0 +1,64 @@
+use dioxus::prelude::*;
+use freya_hooks::{use_get_theme, use_init_theme, use_theme, Theme, DARK_THEME, LIGHT_THEME};
+use freya_testing::prelude::*;
+
+#[tokio::test]
+async fn get_theme_from_signal() {
+    fn theme_app() -> Element {
+        use_context_provider(|| Signal::new(DARK_THEME));
+        rsx!(ThemeName {})
+    }
+
+    #[allow(non_snake_case)]
+    fn ThemeName() -> Element {
+        let theme = use_get_theme();
+
+        rsx!(label { "{theme.name} {theme.button.background}" })
+    }
+
+    let mut utils = launch_test(theme_app);
+
+    utils.wait_for_update().await;
+
+    assert_eq!(
+        utils.root().get(0).get(0).text(),
+        Some("dark rgb(35, 35, 35)")
+    );
+}
+
+#[tokio::test]
+async fn change_theme_tokens() {
+    fn theme_app() -> Element {
+        use_init_theme(LIGHT_THEME);
+        rsx!(ThemeButton {})
+    }
+
+    #[allow(non_snake_case)]
+    fn ThemeButton() -> Element {
+        let mut theme = use_theme();
+        let Theme { button, .. } = use_get_theme();
+
+        rsx!(
+            rect {
+                onclick: move |_| theme.write().tokens.surface = "rgb(0, 0, 255)".into(),
+                label { "{button.background}" }
+            }
+        )
+    }
+
+    let mut utils = launch_test(theme_app);
+    let label = utils.root().get(0).get(0);
+
+    utils.wait_for_update().await;
+
+    assert_eq!(label.get(0).text(), Some("rgb(245, 245, 245)"));
+
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::Click,
+        cursor: (5.0, 5.0).into(),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+
+    assert_eq!(label.get(0).text(), Some("rgb(0, 0, 255)"));
+}
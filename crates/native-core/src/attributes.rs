@@ -11,6 +11,10 @@ pub enum AttributeName {
     Padding,
     Background,
     Border,
+    BorderTop,
+    BorderRight,
+    BorderBottom,
+    BorderLeft,
     BorderAlign,
     Direction,
     Shadow,
@@ -24,6 +28,8 @@ pub enum AttributeName {
     FontWidth,
     MainAlign,
     CrossAlign,
+    AlignSelf,
+    AspectRatio,
     TextAlign,
     TextShadow,
     MaxLines,
@@ -44,6 +50,12 @@ pub enum AttributeName {
     PositionLeft,
     Opacity,
     Content,
+    WrapContent,
+    Spacing,
+    GridColumns,
+    GridRows,
+    GridColumn,
+    GridRow,
     Name,
     Focusable,
     Role,
@@ -63,6 +75,7 @@ pub enum AttributeName {
     HighlightColor,
     ImageReference,
     ImageData,
+    ImageFill,
     SvgData,
     SvgContent,
 }
@@ -81,6 +94,10 @@ impl FromStr for AttributeName {
             "padding" => Ok(AttributeName::Padding),
             "background" => Ok(AttributeName::Background),
             "border" => Ok(AttributeName::Border),
+            "border_top" => Ok(AttributeName::BorderTop),
+            "border_right" => Ok(AttributeName::BorderRight),
+            "border_bottom" => Ok(AttributeName::BorderBottom),
+            "border_left" => Ok(AttributeName::BorderLeft),
             "border_align" => Ok(AttributeName::BorderAlign),
             "direction" => Ok(AttributeName::Direction),
             "shadow" => Ok(AttributeName::Shadow),
@@ -94,6 +111,8 @@ impl FromStr for AttributeName {
             "font_width" => Ok(AttributeName::FontWidth),
             "main_align" => Ok(AttributeName::MainAlign),
             "cross_align" => Ok(AttributeName::CrossAlign),
+            "align_self" => Ok(AttributeName::AlignSelf),
+            "aspect_ratio" => Ok(AttributeName::AspectRatio),
             "text_align" => Ok(AttributeName::TextAlign),
             "text_shadow" => Ok(AttributeName::TextShadow),
             "max_lines" => Ok(AttributeName::MaxLines),
@@ -114,6 +133,12 @@ impl FromStr for AttributeName {
             "position_left" => Ok(AttributeName::PositionLeft),
             "opacity" => Ok(AttributeName::Opacity),
             "content" => Ok(AttributeName::Content),
+            "wrap_content" => Ok(AttributeName::WrapContent),
+            "spacing" => Ok(AttributeName::Spacing),
+            "grid_columns" => Ok(AttributeName::GridColumns),
+            "grid_rows" => Ok(AttributeName::GridRows),
+            "grid_column" => Ok(AttributeName::GridColumn),
+            "grid_row" => Ok(AttributeName::GridRow),
             "name" => Ok(AttributeName::Name),
             "focusable" => Ok(AttributeName::Focusable),
             "role" => Ok(AttributeName::Role),
@@ -133,6 +158,7 @@ impl FromStr for AttributeName {
             "highlight_color" => Ok(AttributeName::HighlightColor),
             "image_reference" => Ok(AttributeName::ImageReference),
             "image_data" => Ok(AttributeName::ImageData),
+            "image_fill" => Ok(AttributeName::ImageFill),
             "svg_data" => Ok(AttributeName::SvgData),
             "svg_content" => Ok(AttributeName::SvgContent),
             _ => Err(format!("{attr} not supported.")),
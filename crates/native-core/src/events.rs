@@ -7,8 +7,10 @@ pub enum EventName {
     Click,
     MiddleClick,
     RightClick,
+    DoubleClick,
 
     MouseDown,
+    MouseUp,
     MouseOver,
     MouseEnter,
     MouseLeave,
@@ -17,6 +19,7 @@ pub enum EventName {
 
     PointerOver,
     PointerDown,
+    PointerMove,
     PointerEnter,
     PointerLeave,
     PointerUp,
@@ -24,11 +27,25 @@ pub enum EventName {
     KeyDown,
     KeyUp,
 
+    Focus,
+    Blur,
+    FocusIn,
+    FocusOut,
+
     TouchCancel,
     TouchStart,
     TouchMove,
     TouchEnd,
 
+    ClickCapture,
+    MouseDownCapture,
+    MouseUpCapture,
+    PointerDownCapture,
+    PointerUpCapture,
+    WheelCapture,
+    KeyDownCapture,
+    KeyUpCapture,
+
     GlobalClick,
     GlobalPointerUp,
     GlobalMouseDown,
@@ -47,22 +64,37 @@ impl FromStr for EventName {
             "click" => Ok(EventName::Click),
             "rightclick" => Ok(EventName::RightClick),
             "middleclick" => Ok(EventName::MiddleClick),
+            "doubleclick" => Ok(EventName::DoubleClick),
             "mousedown" => Ok(EventName::MouseDown),
+            "mouseup" => Ok(EventName::MouseUp),
             "mouseover" => Ok(EventName::MouseOver),
             "mouseenter" => Ok(EventName::MouseEnter),
             "mouseleave" => Ok(EventName::MouseLeave),
             "wheel" => Ok(EventName::Wheel),
             "pointerover" => Ok(EventName::PointerOver),
             "pointerdown" => Ok(EventName::PointerDown),
+            "pointermove" => Ok(EventName::PointerMove),
             "pointerenter" => Ok(EventName::PointerEnter),
             "pointerleave" => Ok(EventName::PointerLeave),
             "pointerup" => Ok(EventName::PointerUp),
             "keydown" => Ok(EventName::KeyDown),
             "keyup" => Ok(EventName::KeyUp),
+            "focus" => Ok(EventName::Focus),
+            "blur" => Ok(EventName::Blur),
+            "focusin" => Ok(EventName::FocusIn),
+            "focusout" => Ok(EventName::FocusOut),
             "touchcancel" => Ok(EventName::TouchCancel),
             "touchstart" => Ok(EventName::TouchStart),
             "touchmove" => Ok(EventName::TouchMove),
             "touchend" => Ok(EventName::TouchEnd),
+            "clickcapture" => Ok(EventName::ClickCapture),
+            "mousedowncapture" => Ok(EventName::MouseDownCapture),
+            "mouseupcapture" => Ok(EventName::MouseUpCapture),
+            "pointerdowncapture" => Ok(EventName::PointerDownCapture),
+            "pointerupcapture" => Ok(EventName::PointerUpCapture),
+            "wheelcapture" => Ok(EventName::WheelCapture),
+            "keydowncapture" => Ok(EventName::KeyDownCapture),
+            "keyupcapture" => Ok(EventName::KeyUpCapture),
             "globalclick" => Ok(EventName::GlobalClick),
             "globalpointerup" => Ok(EventName::GlobalPointerUp),
             "globalmousedown" => Ok(EventName::GlobalMouseDown),
@@ -81,22 +113,37 @@ impl From<EventName> for &str {
             EventName::Click => "click",
             EventName::MiddleClick => "middleclick",
             EventName::RightClick => "rightclick",
+            EventName::DoubleClick => "doubleclick",
             EventName::MouseDown => "mousedown",
+            EventName::MouseUp => "mouseup",
             EventName::MouseOver => "mouseover",
             EventName::MouseEnter => "mouseenter",
             EventName::MouseLeave => "mouseleave",
             EventName::Wheel => "wheel",
             EventName::PointerOver => "pointerover",
             EventName::PointerDown => "pointerdown",
+            EventName::PointerMove => "pointermove",
             EventName::PointerEnter => "pointerenter",
             EventName::PointerLeave => "pointerleave",
             EventName::PointerUp => "pointerup",
             EventName::KeyDown => "keydown",
             EventName::KeyUp => "keyup",
+            EventName::Focus => "focus",
+            EventName::Blur => "blur",
+            EventName::FocusIn => "focusin",
+            EventName::FocusOut => "focusout",
             EventName::TouchCancel => "touchcancel",
             EventName::TouchStart => "touchstart",
             EventName::TouchMove => "touchmove",
             EventName::TouchEnd => "touchend",
+            EventName::ClickCapture => "clickcapture",
+            EventName::MouseDownCapture => "mousedowncapture",
+            EventName::MouseUpCapture => "mouseupcapture",
+            EventName::PointerDownCapture => "pointerdowncapture",
+            EventName::PointerUpCapture => "pointerupcapture",
+            EventName::WheelCapture => "wheelcapture",
+            EventName::KeyDownCapture => "keydowncapture",
+            EventName::KeyUpCapture => "keyupcapture",
             EventName::GlobalClick => "globalclick",
             EventName::GlobalPointerUp => "globalpointerup",
             EventName::GlobalMouseDown => "globalmousedown",
@@ -149,19 +196,23 @@ impl EventName {
     /// Some events might cause other events, like for example:
     /// A `mouseover` might also trigger a `mouseenter`
     /// A `mousedown` or a `touchdown` might also trigger a `pointerdown`
-    pub fn get_collateral_events(&self) -> SmallVec<[Self; 4]> {
+    pub fn get_collateral_events(&self) -> SmallVec<[Self; 5]> {
         let mut events = SmallVec::new();
 
         events.push(*self);
 
         match self {
-            Self::MouseOver | Self::TouchMove => {
-                events.extend([Self::MouseEnter, Self::PointerEnter, Self::PointerOver])
-            }
+            Self::MouseOver | Self::TouchMove => events.extend([
+                Self::MouseEnter,
+                Self::PointerEnter,
+                Self::PointerOver,
+                Self::PointerMove,
+            ]),
             Self::MouseDown | Self::TouchStart => events.push(Self::PointerDown),
-            Self::Click | Self::MiddleClick | Self::RightClick | Self::TouchEnd => {
-                events.push(Self::PointerUp)
+            Self::Click | Self::MiddleClick | Self::RightClick => {
+                events.extend([Self::MouseUp, Self::PointerUp])
             }
+            Self::TouchEnd => events.push(Self::PointerUp),
             Self::MouseLeave => events.push(Self::PointerLeave),
             Self::GlobalFileHover | Self::GlobalFileHoverCancelled => events.clear(),
             _ => {}
@@ -170,6 +221,53 @@ impl EventName {
         events
     }
 
+    /// Get the variant of the event that is dispatched from the root down to the target,
+    /// before the event itself, e.g. `click` -> `clickcapture`
+    pub fn get_capture_event(&self) -> Option<Self> {
+        match self {
+            Self::Click => Some(Self::ClickCapture),
+            Self::MouseDown => Some(Self::MouseDownCapture),
+            Self::MouseUp => Some(Self::MouseUpCapture),
+            Self::PointerDown => Some(Self::PointerDownCapture),
+            Self::PointerUp => Some(Self::PointerUpCapture),
+            Self::Wheel => Some(Self::WheelCapture),
+            Self::KeyDown => Some(Self::KeyDownCapture),
+            Self::KeyUp => Some(Self::KeyUpCapture),
+            _ => None,
+        }
+    }
+
+    /// Get the event captured by a capture variant, e.g. `clickcapture` -> `click`,
+    /// or the event itself if it's not a capture variant
+    pub fn get_captured_event(&self) -> Self {
+        match self {
+            Self::ClickCapture => Self::Click,
+            Self::MouseDownCapture => Self::MouseDown,
+            Self::MouseUpCapture => Self::MouseUp,
+            Self::PointerDownCapture => Self::PointerDown,
+            Self::PointerUpCapture => Self::PointerUp,
+            Self::WheelCapture => Self::Wheel,
+            Self::KeyDownCapture => Self::KeyDown,
+            Self::KeyUpCapture => Self::KeyUp,
+            _ => *self,
+        }
+    }
+
+    /// Check if the event carries the number of consecutive clicks of a mouse button
+    pub fn is_click_counted(&self) -> bool {
+        matches!(
+            &self.get_captured_event(),
+            Self::MouseDown
+                | Self::GlobalMouseDown
+                | Self::MouseUp
+                | Self::Click
+                | Self::GlobalClick
+                | Self::MiddleClick
+                | Self::RightClick
+                | Self::DoubleClick
+        )
+    }
+
     /// Check if the event means that the pointer (e.g. cursor) just entered a Node
     pub fn is_enter(&self) -> bool {
         matches!(&self, Self::MouseEnter | Self::PointerEnter)
@@ -183,8 +281,27 @@ impl EventName {
                 | Self::PointerLeave
                 | Self::PointerOver
                 | Self::PointerDown
+                | Self::PointerMove
                 | Self::PointerUp
                 | Self::GlobalPointerUp
+                | Self::PointerDownCapture
+                | Self::PointerUpCapture
+        )
+    }
+
+    /// Check if the event is delivered to the Node that captured the pointer, if any
+    pub fn can_be_captured(&self) -> bool {
+        matches!(
+            &self,
+            Self::PointerOver | Self::PointerMove | Self::PointerDown | Self::PointerUp
+        )
+    }
+
+    /// Check if the event releases the captured pointer
+    pub fn releases_pointer_capture(&self) -> bool {
+        matches!(
+            &self,
+            Self::Click | Self::MiddleClick | Self::RightClick | Self::TouchEnd | Self::TouchCancel
         )
     }
 
@@ -192,18 +309,34 @@ impl EventName {
     pub fn was_cursor_moved(&self) -> bool {
         matches!(
             &self,
-            Self::MouseOver | Self::MouseEnter | Self::PointerEnter | Self::PointerOver
+            Self::MouseOver
+                | Self::MouseEnter
+                | Self::PointerEnter
+                | Self::PointerOver
+                | Self::PointerMove
         )
     }
 
     // Bubble all events except:
     // - Keyboard events
     // - Mouse movements events
+    // - Focus and blur events
+    // - Capture events, as they are already dispatched to every listening ancestor
     pub fn does_bubble(&self) -> bool {
         !matches!(
             self,
             Self::KeyDown
                 | Self::KeyUp
+                | Self::KeyDownCapture
+                | Self::KeyUpCapture
+                | Self::ClickCapture
+                | Self::MouseDownCapture
+                | Self::MouseUpCapture
+                | Self::PointerDownCapture
+                | Self::PointerUpCapture
+                | Self::WheelCapture
+                | Self::Focus
+                | Self::Blur
                 | Self::MouseLeave
                 | Self::PointerLeave
                 | Self::MouseEnter
@@ -222,7 +355,11 @@ impl EventName {
     pub fn can_change_hover_state(&self) -> bool {
         matches!(
             self,
-            Self::MouseOver | Self::MouseEnter | Self::PointerOver | Self::PointerEnter
+            Self::MouseOver
+                | Self::MouseEnter
+                | Self::PointerOver
+                | Self::PointerMove
+                | Self::PointerEnter
         )
     }
 }
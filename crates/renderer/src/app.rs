@@ -1,13 +1,13 @@
 use dioxus_core::{Template, VirtualDom};
-use freya_common::EventMessage;
+use freya_common::{EventMessage, PreferredTheme};
 use freya_core::prelude::*;
 use freya_engine::prelude::*;
 use freya_hooks::PlatformInformation;
 use freya_native_core::prelude::NodeImmutableDioxusExt;
-use freya_native_core::NodeId;
 use futures_task::Waker;
 use futures_util::FutureExt;
 use pin_utils::pin_mut;
+use std::path::Path;
 use std::sync::{Arc, Mutex};
 use tokio::sync::broadcast;
 use tokio::{
@@ -15,14 +15,14 @@ use tokio::{
     sync::{mpsc, watch, Notify},
 };
 use torin::geometry::{Area, Size2D};
-use tracing::info;
+use tracing::{error, info};
 use uuid::Uuid;
 use winit::dpi::PhysicalSize;
 use winit::event_loop::{EventLoop, EventLoopProxy};
 
 use crate::{
-    accessibility::AccessKitManager, event_loop::run_event_loop, renderer::render_skia,
-    winit_waker::winit_waker,
+    accessibility::AccessKitManager, event_loop::run_event_loop, recorder::EventsRecorder,
+    renderer::render_dom, winit_waker::winit_waker,
 };
 use crate::{EmbeddedFonts, HoveredNode, WindowEnv};
 
@@ -40,6 +40,8 @@ pub struct App<State: 'static + Clone> {
     pub(crate) nodes_state: NodesState,
     pub(crate) focus_sender: FocusSender,
     pub(crate) focus_receiver: FocusReceiver,
+    pub(crate) preferred_theme_sender: PreferredThemeSender,
+    pub(crate) preferred_theme_receiver: PreferredThemeReceiver,
     pub(crate) accessibility: AccessKitManager,
     pub(crate) font_collection: FontCollection,
     pub(crate) font_mgr: FontMgr,
@@ -49,6 +51,7 @@ pub struct App<State: 'static + Clone> {
     pub(crate) measure_layout_on_next_render: bool,
     pub(crate) platform_information: Arc<Mutex<PlatformInformation>>,
     pub(crate) default_fonts: Vec<String>,
+    pub(crate) events_recorder: Option<EventsRecorder>,
 }
 
 impl<State: 'static + Clone> App<State> {
@@ -83,6 +86,8 @@ impl<State: 'static + Clone> App<State> {
 
         let (event_emitter, event_receiver) = mpsc::unbounded_channel::<DomEvent>();
         let (focus_sender, focus_receiver) = watch::channel(ACCESSIBILITY_ROOT_ID);
+        let (preferred_theme_sender, preferred_theme_receiver) =
+            watch::channel(PreferredTheme::default());
 
         plugins.send(PluginEvent::WindowCreated(&window_env.window));
 
@@ -104,6 +109,8 @@ impl<State: 'static + Clone> App<State> {
             accessibility,
             focus_sender,
             focus_receiver,
+            preferred_theme_sender,
+            preferred_theme_receiver,
             font_collection,
             font_mgr,
             ticker_sender: broadcast::channel(5).0,
@@ -112,6 +119,21 @@ impl<State: 'static + Clone> App<State> {
             measure_layout_on_next_render: false,
             platform_information,
             default_fonts,
+            events_recorder: None,
+        }
+    }
+
+    /// Start recording the events of the App into the given file
+    pub fn record_events(&mut self, path: &Path) {
+        let size = self.window_env.window.inner_size();
+        let window_size = Size2D::new(size.width as f32, size.height as f32);
+        let scale_factor = self.window_env.window.scale_factor();
+        match EventsRecorder::new(path, window_size, scale_factor) {
+            Ok(recorder) => {
+                info!("Recording events into {}", path.display());
+                self.events_recorder = Some(recorder);
+            }
+            Err(err) => error!("Failed to record events into {}: {err}", path.display()),
         }
     }
 
@@ -124,6 +146,8 @@ impl<State: 'static + Clone> App<State> {
             .insert_any_root_context(Box::new(self.proxy.clone()));
         self.vdom
             .insert_any_root_context(Box::new(self.focus_receiver.clone()));
+        self.vdom
+            .insert_any_root_context(Box::new(self.preferred_theme_receiver.clone()));
         self.vdom
             .insert_any_root_context(Box::new(Arc::new(self.ticker_sender.subscribe())));
         self.vdom
@@ -172,7 +196,8 @@ impl<State: 'static + Clone> App<State> {
                 let fut = async {
                     select! {
                         ev = self.event_receiver.recv() => {
-                            if let Some(ev) = ev {
+                            // Skip the events that a previous listener stopped from reaching the rest
+                            if let Some(ev) = ev.filter(|ev| !ev.data.is_propagation_stopped()) {
                                 let data = ev.data.any();
                                 let fdom = self.sdom.get();
                                 let rdom = fdom.rdom();
@@ -238,6 +263,9 @@ impl<State: 'static + Clone> App<State> {
 
     /// Send an event
     pub fn send_event(&mut self, event: PlatformEvent) {
+        if let Some(recorder) = &mut self.events_recorder {
+            recorder.record(RecordedEvent::Platform(event.clone()));
+        }
         self.events.push(event);
         self.process_events();
     }
@@ -271,21 +299,65 @@ impl<State: 'static + Clone> App<State> {
 
     /// Resize the Window
     pub fn resize(&mut self, size: PhysicalSize<u32>) {
+        if let Some(recorder) = &mut self.events_recorder {
+            recorder.record(RecordedEvent::Resize(Size2D::new(
+                size.width as f32,
+                size.height as f32,
+            )));
+        }
         self.measure_layout_on_next_render = true;
         self.sdom.get().layout().reset();
         self.window_env.resize(size);
         *self.platform_information.lock().unwrap() = PlatformInformation::from_winit(size);
     }
 
+    /// Notify the app about a new light/dark preference of the platform
+    pub fn set_preferred_theme(&self, preferred_theme: PreferredTheme) {
+        self.preferred_theme_sender.send_if_modified(|current| {
+            let changed = *current != preferred_theme;
+            *current = preferred_theme;
+            changed
+        });
+    }
+
     /// Measure the a text group given it's ID.
     pub fn measure_text_group(&self, text_id: &Uuid) {
         let scale_factor = self.window_env.window.scale_factor() as f32;
         self.sdom.get().measure_paragraphs(text_id, scale_factor);
     }
 
+    /// Focus the given accessibility Node.
+    pub fn focus_node(&self, id: AccessibilityId) {
+        let blurred_id = self.focused_id();
+        self.accessibility
+            .set_accessibility_focus(id, &self.window_env.window);
+        self.emit_focus_events(blurred_id);
+    }
+
+    /// Focus the next or previous accessibility Node.
     pub fn focus_next_node(&self, direction: AccessibilityFocusDirection) {
+        let blurred_id = self.focused_id();
+        self.accessibility
+            .focus_next_node(direction, &self.focus_sender, &self.window_env.window);
+        self.emit_focus_events(blurred_id);
+    }
+
+    fn focused_id(&self) -> AccessibilityId {
         self.accessibility
-            .focus_next_node(direction, &self.focus_sender, &self.window_env.window)
+            .accessibility_manager()
+            .lock()
+            .unwrap()
+            .focused_id
+    }
+
+    /// Notify the Nodes that lost or got the focus.
+    fn emit_focus_events(&self, blurred_id: AccessibilityId) {
+        emit_focus_events(
+            &self.sdom.get(),
+            &self.event_emitter,
+            blurred_id,
+            self.focused_id(),
+        );
     }
 
     /// Notify components subscribed to event loop ticks.
@@ -346,37 +418,13 @@ impl<State: 'static + Clone> App<State> {
         let canvas = self.window_env.canvas();
         let fdom = self.sdom.get();
 
-        let mut matrices: Vec<(Matrix, Vec<NodeId>)> = Vec::default();
-        let mut opacities: Vec<(f32, Vec<NodeId>)> = Vec::default();
-
-        process_render(
+        render_dom(
+            canvas,
             &fdom,
             &mut self.font_collection,
-            |fdom, node_id, area, font_collection, layout| {
-                let render_wireframe = if let Some(hovered_node) = &hovered_node {
-                    hovered_node
-                        .lock()
-                        .unwrap()
-                        .map(|id| id == *node_id)
-                        .unwrap_or_default()
-                } else {
-                    false
-                };
-                if let Some(dioxus_node) = fdom.rdom().get(*node_id) {
-                    render_skia(
-                        canvas,
-                        area,
-                        &dioxus_node,
-                        font_collection,
-                        &self.font_mgr,
-                        render_wireframe,
-                        &mut matrices,
-                        &mut opacities,
-                        &self.default_fonts,
-                        layout,
-                    );
-                }
-            },
+            &self.font_mgr,
+            &self.default_fonts,
+            hovered_node,
         );
     }
 
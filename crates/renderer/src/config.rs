@@ -1,6 +1,9 @@
-use std::{io::Cursor, sync::Arc};
+use std::{io::Cursor, path::PathBuf, sync::Arc, time::Duration};
 
-use freya_core::plugins::{FreyaPlugin, PluginsManager};
+use freya_core::{
+    plugins::{FreyaPlugin, PluginsManager},
+    prelude::{DOUBLE_CLICK_DISTANCE, DOUBLE_CLICK_INTERVAL},
+};
 use freya_engine::prelude::Color;
 use freya_node_state::Parse;
 use image::io::Reader;
@@ -55,6 +58,12 @@ pub struct LaunchConfig<'a, T: Clone> {
     pub embedded_fonts: EmbeddedFonts<'a>,
     pub plugins: PluginsManager,
     pub default_fonts: Vec<String>,
+    /// File where to record the events of the app, so they can be replayed later.
+    pub events_recording: Option<PathBuf>,
+    /// Maximum time between two clicks of a double click.
+    pub double_click_interval: Duration,
+    /// Maximum logical distance between two clicks of a double click.
+    pub double_click_distance: f64,
 }
 
 impl<'a, T: Clone> Default for LaunchConfig<'a, T> {
@@ -64,6 +73,9 @@ impl<'a, T: Clone> Default for LaunchConfig<'a, T> {
             embedded_fonts: Default::default(),
             plugins: Default::default(),
             default_fonts: vec!["Fira Sans".to_string()],
+            events_recording: None,
+            double_click_interval: DOUBLE_CLICK_INTERVAL,
+            double_click_distance: DOUBLE_CLICK_DISTANCE,
         }
     }
 }
@@ -111,6 +123,9 @@ pub struct LaunchConfigBuilder<'a, T> {
     pub(crate) plugins: PluginsManager,
     pub(crate) window_builder_hook: Option<WindowBuilderHook>,
     pub(crate) default_fonts: Vec<String>,
+    pub(crate) events_recording: Option<PathBuf>,
+    pub(crate) double_click_interval: Duration,
+    pub(crate) double_click_distance: f64,
 }
 
 impl<T> Default for LaunchConfigBuilder<'_, T> {
@@ -134,6 +149,9 @@ impl<T> Default for LaunchConfigBuilder<'_, T> {
             plugins: PluginsManager::default(),
             window_builder_hook: None,
             default_fonts: vec!["Fira Sans".to_string()],
+            events_recording: None,
+            double_click_interval: DOUBLE_CLICK_INTERVAL,
+            double_click_distance: DOUBLE_CLICK_DISTANCE,
         }
     }
 }
@@ -256,6 +274,28 @@ impl<'a, T: Clone> LaunchConfigBuilder<'a, T> {
         self
     }
 
+    /// Record the events of the app into the given file, so they can be replayed later with `freya-testing`.
+    ///
+    /// The `FREYA_RECORD_EVENTS` env variable can be used instead to record without changing the code.
+    pub fn with_events_recording(mut self, path: impl Into<PathBuf>) -> Self {
+        self.events_recording = Some(path.into());
+        self
+    }
+
+    /// Specify the maximum time between two clicks to be counted as a double click, 500ms by default.
+    ///
+    /// The setting of the platform is not used, so you can pass it here.
+    pub fn with_double_click_interval(mut self, double_click_interval: Duration) -> Self {
+        self.double_click_interval = double_click_interval;
+        self
+    }
+
+    /// Specify the maximum logical distance the cursor can move between two clicks to be counted as a double click, 4 by default.
+    pub fn with_double_click_distance(mut self, double_click_distance: f64) -> Self {
+        self.double_click_distance = double_click_distance;
+        self
+    }
+
     /// Build the configuration.
     pub fn build(self) -> LaunchConfig<'a, T> {
         LaunchConfig {
@@ -279,6 +319,9 @@ impl<'a, T: Clone> LaunchConfigBuilder<'a, T> {
             embedded_fonts: self.fonts,
             plugins: self.plugins,
             default_fonts: self.default_fonts,
+            events_recording: self.events_recording,
+            double_click_interval: self.double_click_interval,
+            double_click_distance: self.double_click_distance,
         }
     }
 }
@@ -2,7 +2,9 @@ use freya_native_core::real_dom::NodeImmutable;
 
 use freya_core::dom::DioxusNode;
 use freya_engine::prelude::*;
-use freya_node_state::{BorderAlignment, BorderStyle, Fill, References, ShadowPosition, Style};
+use freya_node_state::{
+    Border, BorderAlignment, BorderStyle, Fill, ImageFill, References, ShadowPosition, Style,
+};
 use torin::prelude::Area;
 
 /// Render a `rect` element
@@ -23,14 +25,7 @@ pub fn render_rect(
 
     let area = area.to_f32();
 
-    match &node_style.background {
-        Fill::Color(color) => {
-            paint.set_color(*color);
-        }
-        Fill::LinearGradient(gradient) => {
-            paint.set_shader(gradient.into_shader(area));
-        }
-    }
+    set_fill(&mut paint, &node_style.background, area);
 
     let radius = node_style.corner_radius;
     let rounded_rect = RRect::new_rect_radii(
@@ -55,20 +50,42 @@ pub fn render_rect(
 
     canvas.draw_path(&path, &paint);
 
+    // Background image
+    if let Some(image_data) = &node_style.image_data {
+        if let Some(image) = Image::from_encoded(Data::new_copy(image_data.as_slice())) {
+            let mut image_matrix = Matrix::translate((area.min_x(), area.min_y()));
+            let tile_mode = match node_style.image_fill {
+                ImageFill::Stretch => {
+                    image_matrix.pre_scale(
+                        (
+                            area.width() / image.width() as f32,
+                            area.height() / image.height() as f32,
+                        ),
+                        None,
+                    );
+                    TileMode::Clamp
+                }
+                ImageFill::Tile => TileMode::Repeat,
+            };
+
+            let mut image_paint = Paint::default();
+            image_paint.set_anti_alias(true);
+            image_paint.set_shader(image.to_shader(
+                (tile_mode, tile_mode),
+                FilterMode::Linear,
+                Some(&image_matrix),
+            ));
+            canvas.draw_path(&path, &image_paint);
+        }
+    }
+
     // Shadows
     for shadow in node_style.shadows.iter() {
         if shadow.fill != Fill::Color(Color::TRANSPARENT) {
             let mut shadow_paint = paint.clone();
             let mut shadow_path = Path::new();
 
-            match &shadow.fill {
-                Fill::Color(color) => {
-                    shadow_paint.set_color(*color);
-                }
-                Fill::LinearGradient(gradient) => {
-                    shadow_paint.set_shader(gradient.into_shader(area));
-                }
-            }
+            set_fill(&mut shadow_paint, &shadow.fill, area);
 
             // Shadows can be either outset or inset
             // If they are outset, we fill a copy of the path outset by spread_radius, and blur it.
@@ -126,47 +143,60 @@ pub fn render_rect(
     }
 
     // Borders
-    if node_style.border.width > 0.0 && node_style.border.style != BorderStyle::None {
-        // Create a new paint and path
-        let mut border_paint = paint.clone();
-        let mut border_path = Path::new();
-
-        // Setup paint params
-        border_paint.set_anti_alias(true);
-        border_paint.set_style(PaintStyle::Stroke);
-        match &node_style.border.fill {
-            Fill::Color(color) => {
-                border_paint.set_color(*color);
-            }
-            Fill::LinearGradient(gradient) => {
-                border_paint.set_shader(gradient.into_shader(area));
-            }
+    if node_style.border_sides.is_empty() {
+        if node_style.border.is_visible() {
+            canvas.draw_path(
+                &border_path(node_style, rounded_rect, area, &node_style.border),
+                &border_paint(&node_style.border, area),
+            );
         }
-        border_paint.set_stroke_width(node_style.border.width);
-
-        // Skia draws strokes centered on the edge of the path. This means that half of the stroke is inside the path, and half outside.
-        // For Inner and Outer borders, we need to grow or shrink the stroke path by half the border width.
-        let outset = Point::new(node_style.border.width / 2.0, node_style.border.width / 2.0)
-            * match node_style.border.alignment {
-                BorderAlignment::Center => 0.0,
-                BorderAlignment::Inner => -1.0,
-                BorderAlignment::Outer => 1.0,
+    } else {
+        // Every side is stroked around the whole element but clipped to the region closest to it,
+        // which goes from the center of the element through its corners.
+        let sides = node_style.border_sides.resolve(&node_style.border);
+        let max_width = sides.iter().map(|border| border.width).fold(0.0, f32::max);
+        let extent = max_width * 2.0 + 1.0;
+        let center = Point::new(area.center().x, area.center().y);
+        let corners = [
+            Point::new(area.min_x(), area.min_y()),
+            Point::new(area.max_x(), area.min_y()),
+            Point::new(area.max_x(), area.max_y()),
+            Point::new(area.min_x(), area.max_y()),
+        ];
+        let corner_directions = [
+            Point::new(-1.0, -1.0),
+            Point::new(1.0, -1.0),
+            Point::new(1.0, 1.0),
+            Point::new(-1.0, 1.0),
+        ];
+
+        for (start, border) in sides.into_iter().enumerate() {
+            if !border.is_visible() {
+                continue;
+            }
+            let end = (start + 1) % 4;
+
+            let mut side_path = Path::new();
+            side_path.move_to(center);
+            side_path.line_to(corners[start]);
+            side_path.line_to(corners[start] + corner_directions[start] * extent);
+            side_path.line_to(corners[end] + corner_directions[end] * extent);
+            side_path.line_to(corners[end]);
+            side_path.close();
+
+            let border = Border {
+                alignment: node_style.border.alignment,
+                ..border.clone()
             };
 
-        // Add either the RRect or smoothed path based on whether smoothing is used.
-        if node_style.corner_radius.smoothing > 0.0 {
-            border_path.add_path(
-                &node_style
-                    .corner_radius
-                    .smoothed_path(rounded_rect.with_outset(outset)),
-                Point::new(area.min_x(), area.min_y()) - outset,
-                None,
+            canvas.save();
+            canvas.clip_path(&side_path, ClipOp::Intersect, true);
+            canvas.draw_path(
+                &border_path(node_style, rounded_rect, area, &border),
+                &border_paint(&border, area),
             );
-        } else {
-            border_path.add_rrect(rounded_rect.with_outset(outset), None);
+            canvas.restore();
         }
-
-        canvas.draw_path(&border_path, &border_paint);
     }
 
     let references = node_ref.get::<References>().unwrap();
@@ -175,3 +205,76 @@ pub fn render_rect(
         (canvas_ref.runner)(canvas, font_collection, area);
     }
 }
+
+/// Paint with the given color or gradient
+fn set_fill(paint: &mut Paint, fill: &Fill, area: Area) {
+    match fill {
+        Fill::Color(color) => {
+            paint.set_color(*color);
+        }
+        Fill::LinearGradient(gradient) => {
+            paint.set_shader(gradient.into_shader(area));
+        }
+        Fill::RadialGradient(gradient) => {
+            paint.set_shader(gradient.into_shader(area));
+        }
+        Fill::ConicGradient(gradient) => {
+            paint.set_shader(gradient.into_shader(area));
+        }
+    }
+}
+
+/// Path over which the given border is stroked
+fn border_path(node_style: &Style, rounded_rect: RRect, area: Area, border: &Border) -> Path {
+    let mut border_path = Path::new();
+
+    // Skia draws strokes centered on the edge of the path. This means that half of the stroke is inside the path, and half outside.
+    // For Inner and Outer borders, we need to grow or shrink the stroke path by half the border width.
+    let outset = Point::new(border.width / 2.0, border.width / 2.0)
+        * match border.alignment {
+            BorderAlignment::Center => 0.0,
+            BorderAlignment::Inner => -1.0,
+            BorderAlignment::Outer => 1.0,
+        };
+
+    // Add either the RRect or smoothed path based on whether smoothing is used.
+    if node_style.corner_radius.smoothing > 0.0 {
+        border_path.add_path(
+            &node_style
+                .corner_radius
+                .smoothed_path(rounded_rect.with_outset(outset)),
+            Point::new(area.min_x(), area.min_y()) - outset,
+            None,
+        );
+    } else {
+        border_path.add_rrect(rounded_rect.with_outset(outset), None);
+    }
+
+    border_path
+}
+
+/// Paint to stroke the given border with
+fn border_paint(border: &Border, area: Area) -> Paint {
+    let mut border_paint = Paint::default();
+    border_paint.set_anti_alias(true);
+    border_paint.set_style(PaintStyle::Stroke);
+    set_fill(&mut border_paint, &border.fill, area);
+    border_paint.set_stroke_width(border.width);
+
+    match border.style {
+        BorderStyle::Dashed => {
+            border_paint.set_path_effect(PathEffect::dash(
+                &[border.width * 3.0, border.width * 3.0],
+                0.0,
+            ));
+        }
+        BorderStyle::Dotted => {
+            // Round caps turn the empty dashes into dots
+            border_paint.set_stroke_cap(PaintCap::Round);
+            border_paint.set_path_effect(PathEffect::dash(&[0.0, border.width * 2.0], 0.0));
+        }
+        BorderStyle::Solid | BorderStyle::None => {}
+    }
+
+    border_paint
+}
@@ -37,14 +37,17 @@ pub fn run_event_loop<State: Clone>(
     event_loop
         .run(move |event, event_loop| match event {
             Event::NewEvents(StartCause::Init) => {
+                // Some platforms, like most Linux desktops, don't report a preference
+                if let Some(theme) = app.window_env.window.theme() {
+                    _ = proxy.send_event(EventMessage::PreferredThemeChanged(theme.into()));
+                }
                 _ = proxy.send_event(EventMessage::PollVDOM);
             }
             Event::UserEvent(EventMessage::ExitApp) => {
                 event_loop.exit();
             }
             Event::UserEvent(EventMessage::FocusAccessibilityNode(id)) => {
-                app.accessibility
-                    .set_accessibility_focus(id, &app.window_env.window);
+                app.focus_node(id);
             }
             Event::UserEvent(EventMessage::RequestRerender) => {
                 app.window_env.window.request_redraw();
@@ -57,8 +60,7 @@ pub fn run_event_loop<State: Clone>(
                 ..
             })) => {
                 if Action::Focus == request.action {
-                    app.accessibility
-                        .set_accessibility_focus(request.target, &app.window_env.window);
+                    app.focus_node(request.target);
                 }
             }
             Event::UserEvent(EventMessage::SetCursorIcon(icon)) => {
@@ -78,6 +80,9 @@ pub fn run_event_loop<State: Clone>(
             Event::UserEvent(EventMessage::SetWindowSize(window_size)) => {
                 let _ = app.window_env.window.request_inner_size(LogicalSize::new(window_size.width, window_size.height));
             }
+            Event::UserEvent(EventMessage::PreferredThemeChanged(preferred_theme)) => {
+                app.set_preferred_theme(preferred_theme);
+            }
             Event::UserEvent(ev) => {
                 if let EventMessage::UpdateTemplate(template) = ev {
                     app.vdom_replace_template(template);
@@ -94,6 +99,9 @@ pub fn run_event_loop<State: Clone>(
                     .process_accessibility_event(&event, &app.window_env.window);
                 match event {
                     WindowEvent::CloseRequested => event_loop.exit(),
+                    WindowEvent::ThemeChanged(theme) => {
+                        _ = proxy.send_event(EventMessage::PreferredThemeChanged(theme.into()));
+                    }
                     WindowEvent::Ime(Ime::Commit(text)) => {
                         app.send_event(PlatformEvent::Keyboard {
                             name: EventName::KeyDown,
@@ -4,11 +4,16 @@ use dioxus_core::VirtualDom;
 use freya_common::EventMessage;
 use freya_core::dom::SafeDOM;
 use freya_native_core::NodeId;
-use std::sync::{Arc, Mutex};
+use std::{
+    path::PathBuf,
+    sync::{Arc, Mutex},
+};
 use tokio::sync::Notify;
 use winit::event_loop::EventLoopBuilder;
 
 pub use config::WindowConfig;
+pub use recorder::RECORD_EVENTS_ENV;
+pub use renderer::{render_dom, render_skia};
 pub use window::WindowEnv;
 
 mod accessibility;
@@ -16,6 +21,7 @@ mod app;
 mod config;
 mod elements;
 mod event_loop;
+mod recorder;
 mod renderer;
 mod window;
 mod winit_waker;
@@ -72,6 +78,17 @@ impl DesktopRenderer {
             config.plugins,
             config.default_fonts,
         );
+        app.nodes_state
+            .set_double_click_interval(config.double_click_interval);
+        app.nodes_state
+            .set_double_click_distance(config.double_click_distance);
+
+        let events_recording = config
+            .events_recording
+            .or_else(|| std::env::var_os(RECORD_EVENTS_ENV).map(PathBuf::from));
+        if let Some(path) = events_recording {
+            app.record_events(&path);
+        }
 
         app.init_doms();
         app.process_layout();
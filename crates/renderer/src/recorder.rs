@@ -0,0 +1,42 @@
+use std::fs::File;
+use std::io::{self, LineWriter, Write};
+use std::path::Path;
+use std::time::Instant;
+
+use freya_core::prelude::{EventsRecording, RecordedEntry, RecordedEvent};
+use torin::geometry::Size2D;
+use tracing::error;
+
+/// Env variable used to record the events of an app into the given file.
+pub const RECORD_EVENTS_ENV: &str = "FREYA_RECORD_EVENTS";
+
+/// Writes the events of the app into a file as they happen, so it can be replayed even if the app crashes.
+pub(crate) struct EventsRecorder {
+    writer: LineWriter<File>,
+    start: Instant,
+}
+
+impl EventsRecorder {
+    pub fn new(path: &Path, window_size: Size2D, scale_factor: f64) -> io::Result<Self> {
+        let mut writer = LineWriter::new(File::create(path)?);
+        write!(
+            writer,
+            "{}",
+            EventsRecording::new(window_size, scale_factor)
+        )?;
+        Ok(Self {
+            writer,
+            start: Instant::now(),
+        })
+    }
+
+    pub fn record(&mut self, event: RecordedEvent) {
+        let entry = RecordedEntry {
+            time: self.start.elapsed(),
+            event,
+        };
+        if let Err(err) = writeln!(self.writer, "{entry}") {
+            error!("Failed to record event: {err}");
+        }
+    }
+}
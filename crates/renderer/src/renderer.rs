@@ -3,7 +3,8 @@ use freya_native_core::real_dom::NodeImmutable;
 use freya_native_core::NodeId;
 use freya_native_core::{node::NodeType, tags::TagName};
 
-use freya_core::dom::DioxusNode;
+use freya_core::dom::{DioxusNode, FreyaDOM};
+use freya_core::render::process_render;
 use freya_engine::prelude::*;
 use freya_node_state::{Style, Transform, ViewportState};
 use torin::{
@@ -12,6 +13,7 @@ use torin::{
 };
 
 use crate::elements::{render_image, render_label, render_paragraph, render_rect, render_svg};
+use crate::HoveredNode;
 
 fn clip_viewport(canvas: &Canvas, viewport: &Area) {
     canvas.clip_rect(
@@ -26,6 +28,49 @@ fn clip_viewport(canvas: &Canvas, viewport: &Area) {
     );
 }
 
+/// Render all the visible Nodes of a DOM into the Skia canvas
+pub fn render_dom(
+    canvas: &Canvas,
+    fdom: &FreyaDOM,
+    font_collection: &mut FontCollection,
+    font_manager: &FontMgr,
+    default_fonts: &[String],
+    hovered_node: &HoveredNode,
+) {
+    let mut matrices: Vec<(Matrix, Vec<NodeId>)> = Vec::default();
+    let mut opacities: Vec<(f32, Vec<NodeId>)> = Vec::default();
+
+    process_render(
+        fdom,
+        font_collection,
+        |fdom, node_id, area, font_collection, layout| {
+            let render_wireframe = if let Some(hovered_node) = &hovered_node {
+                hovered_node
+                    .lock()
+                    .unwrap()
+                    .map(|id| id == *node_id)
+                    .unwrap_or_default()
+            } else {
+                false
+            };
+            if let Some(dioxus_node) = fdom.rdom().get(*node_id) {
+                render_skia(
+                    canvas,
+                    area,
+                    &dioxus_node,
+                    font_collection,
+                    font_manager,
+                    render_wireframe,
+                    &mut matrices,
+                    &mut opacities,
+                    default_fonts,
+                    layout,
+                );
+            }
+        },
+    );
+}
+
 /// Render a node into the Skia canvas
 #[allow(clippy::too_many_arguments)]
 pub fn render_skia(
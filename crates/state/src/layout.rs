@@ -11,7 +11,7 @@ use freya_native_core::{
 use freya_native_core_macro::partial_derive_state;
 use torin::prelude::*;
 
-use crate::{CustomAttributeValues, NodeReference, Parse};
+use crate::{parse_aspect_ratio, parse_grid_tracks, CustomAttributeValues, NodeReference, Parse};
 
 #[derive(Default, Clone, Debug, Component, PartialEq)]
 pub struct LayoutState {
@@ -28,8 +28,16 @@ pub struct LayoutState {
     pub offset_x: Length,
     pub main_alignment: Alignment,
     pub cross_alignment: Alignment,
+    pub align_self: Option<Alignment>,
+    pub aspect_ratio: Option<f32>,
     pub position: Position,
     pub content: Content,
+    pub wrap_content: WrapContent,
+    pub spacing: Gaps,
+    pub grid_columns: Vec<GridTrack>,
+    pub grid_rows: Vec<GridTrack>,
+    pub grid_column: GridPlacement,
+    pub grid_row: GridPlacement,
     pub node_ref: Option<NodeReference>,
     pub node_id: NodeId,
 }
@@ -56,6 +64,8 @@ impl State<CustomAttributeValues> for LayoutState {
             AttributeName::OffsetY,
             AttributeName::MainAlign,
             AttributeName::CrossAlign,
+            AttributeName::AlignSelf,
+            AttributeName::AspectRatio,
             AttributeName::Reference,
             AttributeName::Margin,
             AttributeName::Position,
@@ -64,6 +74,12 @@ impl State<CustomAttributeValues> for LayoutState {
             AttributeName::PositionBottom,
             AttributeName::PositionLeft,
             AttributeName::Content,
+            AttributeName::WrapContent,
+            AttributeName::Spacing,
+            AttributeName::GridColumns,
+            AttributeName::GridRows,
+            AttributeName::GridColumn,
+            AttributeName::GridRow,
         ]));
 
     fn update<'a>(
@@ -181,7 +197,28 @@ impl State<CustomAttributeValues> for LayoutState {
                     AttributeName::CrossAlign => {
                         if let Some(value) = attr.value.as_text() {
                             if let Ok(alignment) = Alignment::parse(value) {
-                                layout.cross_alignment = alignment;
+                                // The spaced alignments only apply to the Main axis
+                                if !alignment.is_spaced() {
+                                    layout.cross_alignment = alignment;
+                                }
+                            }
+                        }
+                    }
+                    AttributeName::AlignSelf => {
+                        if let Some(value) = attr.value.as_text() {
+                            if value != "auto" {
+                                if let Ok(alignment) = Alignment::parse(value) {
+                                    if !alignment.is_spaced() {
+                                        layout.align_self = Some(alignment);
+                                    }
+                                }
+                            }
+                        }
+                    }
+                    AttributeName::AspectRatio => {
+                        if let Some(value) = attr.value.as_text() {
+                            if let Ok(aspect_ratio) = parse_aspect_ratio(value) {
+                                layout.aspect_ratio = Some(aspect_ratio);
                             }
                         }
                     }
@@ -229,6 +266,58 @@ impl State<CustomAttributeValues> for LayoutState {
                             }
                         }
                     }
+                    AttributeName::WrapContent => {
+                        if let Some(value) = attr.value.as_text() {
+                            if let Ok(wrap_content) = WrapContent::parse(value) {
+                                layout.wrap_content = wrap_content;
+                            }
+                        }
+                    }
+                    AttributeName::Spacing => {
+                        if let Some(value) = attr.value.as_text() {
+                            // Only the vertical and horizontal spaces are supported
+                            if value.split_ascii_whitespace().count() <= 2 {
+                                if let Ok(mut spacing) = Gaps::parse(value) {
+                                    spacing.scale(*scale_factor);
+                                    layout.spacing = spacing;
+                                }
+                            }
+                        }
+                    }
+                    AttributeName::GridColumns => {
+                        if let Some(value) = attr.value.as_text() {
+                            if let Ok(mut grid_columns) = parse_grid_tracks(value) {
+                                grid_columns
+                                    .iter_mut()
+                                    .for_each(|track| track.scale(*scale_factor));
+                                layout.grid_columns = grid_columns;
+                            }
+                        }
+                    }
+                    AttributeName::GridRows => {
+                        if let Some(value) = attr.value.as_text() {
+                            if let Ok(mut grid_rows) = parse_grid_tracks(value) {
+                                grid_rows
+                                    .iter_mut()
+                                    .for_each(|track| track.scale(*scale_factor));
+                                layout.grid_rows = grid_rows;
+                            }
+                        }
+                    }
+                    AttributeName::GridColumn => {
+                        if let Some(value) = attr.value.as_text() {
+                            if let Ok(grid_column) = GridPlacement::parse(value) {
+                                layout.grid_column = grid_column;
+                            }
+                        }
+                    }
+                    AttributeName::GridRow => {
+                        if let Some(value) = attr.value.as_text() {
+                            if let Ok(grid_row) = GridPlacement::parse(value) {
+                                layout.grid_row = grid_row;
+                            }
+                        }
+                    }
                     AttributeName::Reference => {
                         if let OwnedAttributeValue::Custom(CustomAttributeValues::Reference(
                             reference,
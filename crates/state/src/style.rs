@@ -10,17 +10,19 @@ use freya_native_core_macro::partial_derive_state;
 use torin::scaled::Scaled;
 
 use crate::{
-    parsing::ExtSplit, AttributesBytes, Border, BorderAlignment, CornerRadius,
-    CustomAttributeValues, Fill, OverflowMode, Parse, Shadow,
+    parsing::ExtSplit, AttributesBytes, Border, BorderAlignment, BorderSides, CornerRadius,
+    CustomAttributeValues, Fill, ImageFill, OverflowMode, Parse, Shadow,
 };
 
 #[derive(Default, Debug, Clone, PartialEq, Component)]
 pub struct Style {
     pub background: Fill,
     pub border: Border,
+    pub border_sides: BorderSides,
     pub shadows: Vec<Shadow>,
     pub corner_radius: CornerRadius,
     pub image_data: Option<AttributesBytes>,
+    pub image_fill: ImageFill,
     pub svg_data: Option<AttributesBytes>,
     pub overflow: OverflowMode,
     pub opacity: Option<f32>,
@@ -39,11 +41,16 @@ impl State<CustomAttributeValues> for Style {
             AttributeName::Background,
             AttributeName::Layer,
             AttributeName::Border,
+            AttributeName::BorderTop,
+            AttributeName::BorderRight,
+            AttributeName::BorderBottom,
+            AttributeName::BorderLeft,
             AttributeName::BorderAlign,
             AttributeName::Shadow,
             AttributeName::CornerRadius,
             AttributeName::CornerSmoothing,
             AttributeName::ImageData,
+            AttributeName::ImageFill,
             AttributeName::SvgData,
             AttributeName::SvgContent,
             AttributeName::Overflow,
@@ -81,6 +88,24 @@ impl State<CustomAttributeValues> for Style {
                             }
                         }
                     }
+                    AttributeName::BorderTop
+                    | AttributeName::BorderRight
+                    | AttributeName::BorderBottom
+                    | AttributeName::BorderLeft => {
+                        if let Some(value) = attr.value.as_text() {
+                            if let Ok(mut border) = Border::parse(value) {
+                                border.scale(*scale_factor);
+
+                                let side = match attr.attribute {
+                                    AttributeName::BorderTop => &mut style.border_sides.top,
+                                    AttributeName::BorderRight => &mut style.border_sides.right,
+                                    AttributeName::BorderBottom => &mut style.border_sides.bottom,
+                                    _ => &mut style.border_sides.left,
+                                };
+                                *side = Some(border);
+                            }
+                        }
+                    }
                     AttributeName::BorderAlign => {
                         if let Some(value) = attr.value.as_text() {
                             if let Ok(alignment) = BorderAlignment::parse(value) {
@@ -126,6 +151,13 @@ impl State<CustomAttributeValues> for Style {
                             style.image_data = Some(bytes.clone());
                         }
                     }
+                    AttributeName::ImageFill => {
+                        if let Some(value) = attr.value.as_text() {
+                            if let Ok(image_fill) = ImageFill::parse(value) {
+                                style.image_fill = image_fill;
+                            }
+                        }
+                    }
                     AttributeName::SvgData => {
                         if let OwnedAttributeValue::Custom(CustomAttributeValues::Bytes(bytes)) =
                             attr.value
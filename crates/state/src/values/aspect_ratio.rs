@@ -0,0 +1,24 @@
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseAspectRatioError;
+
+/// Parse an aspect ratio, either as a number (e.g `1.5`) or as a fraction (e.g `16 / 9`)
+pub fn parse_aspect_ratio(value: &str) -> Result<f32, ParseAspectRatioError> {
+    let parse_number = |value: &str| {
+        value
+            .trim()
+            .parse::<f32>()
+            .map_err(|_| ParseAspectRatioError)
+    };
+
+    let ratio = if let Some((width, height)) = value.split_once('/') {
+        parse_number(width)? / parse_number(height)?
+    } else {
+        parse_number(value)?
+    };
+
+    if ratio.is_finite() && ratio > 0.0 {
+        Ok(ratio)
+    } else {
+        Err(ParseAspectRatioError)
+    }
+}
@@ -7,6 +7,8 @@ pub enum BorderStyle {
     #[default]
     None,
     Solid,
+    Dashed,
+    Dotted,
 }
 
 #[derive(Default, Clone, Debug, PartialEq)]
@@ -17,6 +19,35 @@ pub struct Border {
     pub alignment: BorderAlignment,
 }
 
+impl Border {
+    /// Whether the border is drawn at all.
+    pub fn is_visible(&self) -> bool {
+        self.width > 0.0 && self.style != BorderStyle::None
+    }
+}
+
+/// Borders of individual sides, which take precedence over the border of all sides.
+#[derive(Default, Clone, Debug, PartialEq)]
+pub struct BorderSides {
+    pub top: Option<Border>,
+    pub right: Option<Border>,
+    pub bottom: Option<Border>,
+    pub left: Option<Border>,
+}
+
+impl BorderSides {
+    /// Whether none of the sides has its own border.
+    pub fn is_empty(&self) -> bool {
+        self.top.is_none() && self.right.is_none() && self.bottom.is_none() && self.left.is_none()
+    }
+
+    /// Get the borders of the top, right, bottom and left sides, falling back to `border` for the sides without their own.
+    pub fn resolve<'a>(&'a self, border: &'a Border) -> [&'a Border; 4] {
+        [&self.top, &self.right, &self.bottom, &self.left]
+            .map(|side| side.as_ref().unwrap_or(border))
+    }
+}
+
 #[derive(Default, Clone, Copy, Debug, PartialEq)]
 pub enum BorderAlignment {
     #[default]
@@ -55,6 +86,8 @@ impl fmt::Display for BorderStyle {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         f.write_str(match self {
             BorderStyle::Solid => "solid",
+            BorderStyle::Dashed => "dashed",
+            BorderStyle::Dotted => "dotted",
             BorderStyle::None => "none",
         })
     }
@@ -77,6 +110,8 @@ impl Parse for Border {
                 .map_err(|_| ParseBorderError)?,
             style: match border_values.next().ok_or(ParseBorderError)? {
                 "solid" => BorderStyle::Solid,
+                "dashed" => BorderStyle::Dashed,
+                "dotted" => BorderStyle::Dotted,
                 _ => BorderStyle::None,
             },
             fill: Fill::parse(&border_values.collect::<Vec<&str>>().join(" "))
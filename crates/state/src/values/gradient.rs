@@ -86,43 +86,140 @@ impl Parse for LinearGradient {
     type Err = ParseLinearGradientError;
 
     fn parse(value: &str) -> Result<Self, Self::Err> {
-        if !value.starts_with("linear-gradient(") || !value.ends_with(')') {
-            return Err(ParseLinearGradientError);
-        }
+        let (angle, stops) =
+            parse_gradient("linear-gradient", value).ok_or(ParseLinearGradientError)?;
 
-        let mut gradient = LinearGradient::default();
-        let mut value = value.replacen("linear-gradient(", "", 1);
-        value.remove(value.rfind(')').ok_or(ParseLinearGradientError)?);
+        Ok(LinearGradient {
+            stops,
+            angle: angle.unwrap_or_default(),
+        })
+    }
+}
 
-        let mut split = value.split_excluding_group(',', '(', ')');
+impl fmt::Display for LinearGradient {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "linear-gradient({}deg, {})",
+            self.angle.to_degrees(),
+            self.stops
+                .iter()
+                .map(|stop| stop.to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        )
+    }
+}
 
-        let angle_or_first_stop = split.next().ok_or(ParseLinearGradientError)?.trim();
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RadialGradient {
+    pub stops: Vec<GradientStop>,
+}
 
-        if angle_or_first_stop.ends_with("deg") {
-            if let Ok(angle) = angle_or_first_stop.replacen("deg", "", 1).parse::<f32>() {
-                gradient.angle = angle.to_radians();
-            }
-        } else {
-            gradient.stops.push(
-                GradientStop::parse(angle_or_first_stop).map_err(|_| ParseLinearGradientError)?,
-            );
-        }
+impl RadialGradient {
+    /// Distance from the center of the bounds to their corners,
+    /// so the last stop reaches the farthest corner like the default of CSS `radial-gradient`.
+    pub fn radius(bounds: Rect<f32, Measure>) -> f32 {
+        bounds.width().hypot(bounds.height()) / 2.0
+    }
 
-        for stop in split {
-            gradient
-                .stops
-                .push(GradientStop::parse(stop).map_err(|_| ParseLinearGradientError)?);
+    pub fn into_shader(&self, bounds: Rect<f32, Measure>) -> Option<Shader> {
+        let colors: Vec<Color> = self.stops.iter().map(|stop| stop.color).collect();
+        let offsets: Vec<f32> = self.stops.iter().map(|stop| stop.offset).collect();
+
+        let center = bounds.center();
+        Shader::radial_gradient(
+            Point::new(center.x, center.y),
+            Self::radius(bounds),
+            GradientShaderColors::Colors(&colors[..]),
+            Some(&offsets[..]),
+            TileMode::Clamp,
+            None,
+            None,
+        )
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseRadialGradientError;
+
+impl Parse for RadialGradient {
+    type Err = ParseRadialGradientError;
+
+    fn parse(value: &str) -> Result<Self, Self::Err> {
+        match parse_gradient("radial-gradient", value) {
+            Some((None, stops)) => Ok(RadialGradient { stops }),
+            _ => Err(ParseRadialGradientError),
         }
+    }
+}
 
-        Ok(gradient)
+impl fmt::Display for RadialGradient {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "radial-gradient({})",
+            self.stops
+                .iter()
+                .map(|stop| stop.to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        )
     }
 }
 
-impl fmt::Display for LinearGradient {
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ConicGradient {
+    pub stops: Vec<GradientStop>,
+    /// Rotation of the gradient, starting from the top and going clockwise.
+    pub angle: f32,
+}
+
+impl ConicGradient {
+    pub fn into_shader(&self, bounds: Rect<f32, Measure>) -> Option<Shader> {
+        let colors: Vec<Color> = self.stops.iter().map(|stop| stop.color).collect();
+        let offsets: Vec<f32> = self.stops.iter().map(|stop| stop.offset).collect();
+
+        // Skia sweeps start from the right, so rotate them to start from the top
+        let center = bounds.center();
+        let center = Point::new(center.x, center.y);
+        let mut matrix = Matrix::new_identity();
+        matrix.set_rotate(self.angle.to_degrees() - 90.0, center);
+
+        Shader::sweep_gradient(
+            center,
+            GradientShaderColors::Colors(&colors[..]),
+            Some(&offsets[..]),
+            TileMode::Clamp,
+            None,
+            None,
+            Some(&matrix),
+        )
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseConicGradientError;
+
+impl Parse for ConicGradient {
+    type Err = ParseConicGradientError;
+
+    fn parse(value: &str) -> Result<Self, Self::Err> {
+        let (angle, stops) =
+            parse_gradient("conic-gradient", value).ok_or(ParseConicGradientError)?;
+
+        Ok(ConicGradient {
+            stops,
+            angle: angle.unwrap_or_default(),
+        })
+    }
+}
+
+impl fmt::Display for ConicGradient {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(
             f,
-            "linear-gradient({}deg, {})",
+            "conic-gradient({}deg, {})",
             self.angle.to_degrees(),
             self.stops
                 .iter()
@@ -132,3 +229,34 @@ impl fmt::Display for LinearGradient {
         )
     }
 }
+
+/// Parse a gradient function like `linear-gradient(45deg, red 0%, blue 100%)`
+/// into its optional angle, in radians, and its color stops.
+fn parse_gradient(name: &str, value: &str) -> Option<(Option<f32>, Vec<GradientStop>)> {
+    let value = value
+        .strip_prefix(name)?
+        .strip_prefix('(')?
+        .strip_suffix(')')?;
+
+    let mut split = value.split_excluding_group(',', '(', ')');
+    let mut angle = None;
+    let mut stops = Vec::new();
+
+    let angle_or_first_stop = split.next()?.trim();
+
+    if angle_or_first_stop.ends_with("deg") {
+        let degrees = angle_or_first_stop
+            .replacen("deg", "", 1)
+            .parse::<f32>()
+            .unwrap_or_default();
+        angle = Some(degrees.to_radians());
+    } else {
+        stops.push(GradientStop::parse(angle_or_first_stop).ok()?);
+    }
+
+    for stop in split {
+        stops.push(GradientStop::parse(stop).ok()?);
+    }
+
+    Some((angle, stops))
+}
@@ -0,0 +1,77 @@
+use torin::{
+    geometry::Length,
+    grid::{GridPlacement, GridTrack},
+};
+
+use crate::Parse;
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseGridError;
+
+impl Parse for GridTrack {
+    type Err = ParseGridError;
+
+    fn parse(value: &str) -> Result<Self, Self::Err> {
+        if value == "auto" {
+            Ok(GridTrack::Auto)
+        } else if let Some(fraction) = value.strip_suffix("fr") {
+            Ok(GridTrack::Fraction(Length::new(
+                fraction.parse::<f32>().map_err(|_| ParseGridError)?,
+            )))
+        } else if let Some(percentage) = value.strip_suffix('%') {
+            Ok(GridTrack::Percentage(Length::new(
+                percentage.parse::<f32>().map_err(|_| ParseGridError)?,
+            )))
+        } else {
+            Ok(GridTrack::Pixels(Length::new(
+                value.parse::<f32>().map_err(|_| ParseGridError)?,
+            )))
+        }
+    }
+}
+
+/// Parse a list of grid tracks separated by whitespaces, e.g `100 1fr auto`
+pub fn parse_grid_tracks(value: &str) -> Result<Vec<GridTrack>, ParseGridError> {
+    value
+        .split_ascii_whitespace()
+        .map(GridTrack::parse)
+        .collect()
+}
+
+impl Parse for GridPlacement {
+    type Err = ParseGridError;
+
+    fn parse(value: &str) -> Result<Self, Self::Err> {
+        let parse_span = |span: &str| {
+            span.trim()
+                .strip_prefix("span")
+                .ok_or(ParseGridError)?
+                .trim()
+                .parse::<usize>()
+                .map_err(|_| ParseGridError)
+        };
+
+        let mut parts = value.split('/');
+        let start = parts.next().ok_or(ParseGridError)?.trim();
+        let span = parts.next();
+
+        if parts.next().is_some() {
+            return Err(ParseGridError);
+        }
+
+        if start == "auto" {
+            Ok(GridPlacement::default())
+        } else if start.starts_with("span") && span.is_none() {
+            Ok(GridPlacement::new(None, parse_span(start)?))
+        } else {
+            // Tracks are 1-based in the attributes, like in CSS
+            let start = start
+                .parse::<usize>()
+                .ok()
+                .and_then(|start| start.checked_sub(1))
+                .ok_or(ParseGridError)?;
+            let span = span.map(parse_span).transpose()?.unwrap_or(1);
+            Ok(GridPlacement::new(Some(start), span))
+        }
+    }
+}
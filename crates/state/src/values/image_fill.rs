@@ -0,0 +1,36 @@
+use crate::Parse;
+use std::fmt;
+
+/// How the `image_data` of a `rect` fills its background.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum ImageFill {
+    /// Scale the image to cover the whole element.
+    #[default]
+    Stretch,
+    /// Repeat the image at its original size.
+    Tile,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseImageFillError;
+
+impl Parse for ImageFill {
+    type Err = ParseImageFillError;
+
+    fn parse(value: &str) -> Result<Self, Self::Err> {
+        Ok(match value {
+            "stretch" => ImageFill::Stretch,
+            "tile" => ImageFill::Tile,
+            _ => return Err(ParseImageFillError),
+        })
+    }
+}
+
+impl fmt::Display for ImageFill {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            ImageFill::Stretch => "stretch",
+            ImageFill::Tile => "tile",
+        })
+    }
+}
@@ -1,4 +1,5 @@
 mod alignment;
+mod aspect_ratio;
 mod border;
 mod color;
 mod content;
@@ -9,13 +10,17 @@ mod fill;
 mod font;
 mod gaps;
 mod gradient;
+mod grid;
+mod image_fill;
 mod overflow;
 mod position;
 mod shadow;
 mod size;
 mod text_shadow;
+mod wrap_content;
 
 pub use alignment::*;
+pub use aspect_ratio::*;
 pub use border::*;
 pub use color::*;
 pub use content::*;
@@ -26,8 +31,11 @@ pub use fill::*;
 pub use font::*;
 pub use gaps::*;
 pub use gradient::*;
+pub use grid::*;
+pub use image_fill::*;
 pub use overflow::*;
 pub use position::*;
 pub use shadow::*;
 pub use size::*;
 pub use text_shadow::*;
+pub use wrap_content::*;
@@ -18,6 +18,16 @@ impl Parse for Size {
             Ok(Size::FillMinimum)
         } else if value.contains("calc") {
             Ok(Size::DynamicCalculations(Box::new(parse_calc(value)?)))
+        } else if value.contains("flex") {
+            Ok(Size::Flex(Length::new(
+                value
+                    .strip_prefix("flex(")
+                    .ok_or(ParseSizeError)?
+                    .strip_suffix(')')
+                    .ok_or(ParseSizeError)?
+                    .parse::<f32>()
+                    .map_err(|_| ParseSizeError)?,
+            )))
         } else if value.contains('%') {
             Ok(Size::Percentage(Length::new(
                 value
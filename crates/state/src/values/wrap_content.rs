@@ -0,0 +1,17 @@
+use torin::wrap_content::WrapContent;
+
+use crate::Parse;
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseWrapContentError;
+
+impl Parse for WrapContent {
+    type Err = ParseWrapContentError;
+
+    fn parse(value: &str) -> Result<Self, Self::Err> {
+        Ok(match value {
+            "wrap" => WrapContent::Wrap,
+            _ => WrapContent::NoWrap,
+        })
+    }
+}
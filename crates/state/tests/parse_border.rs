@@ -1,6 +1,6 @@
 use freya_engine::prelude::*;
 use freya_node_state::{
-    Border, BorderAlignment, BorderStyle, Fill, GradientStop, LinearGradient, Parse,
+    Border, BorderAlignment, BorderSides, BorderStyle, Fill, GradientStop, LinearGradient, Parse,
 };
 
 #[test]
@@ -83,3 +83,42 @@ fn parse_border_style() {
     );
     assert!(invalid.is_err());
 }
+
+#[test]
+fn parse_border_dashed_dotted() {
+    let dashed = Border::parse("1 dashed red");
+    let dotted = Border::parse("1 dotted red");
+
+    assert_eq!(
+        dashed,
+        Ok(Border {
+            width: 1.0,
+            fill: Fill::Color(Color::RED),
+            style: BorderStyle::Dashed,
+            alignment: BorderAlignment::default()
+        })
+    );
+    assert_eq!(
+        dotted,
+        Ok(Border {
+            width: 1.0,
+            fill: Fill::Color(Color::RED),
+            style: BorderStyle::Dotted,
+            alignment: BorderAlignment::default()
+        })
+    );
+}
+
+#[test]
+fn resolve_border_sides() {
+    let border = Border::parse("1 solid red").unwrap();
+    let bottom = Border::parse("2 dashed blue").unwrap();
+    let sides = BorderSides {
+        bottom: Some(bottom.clone()),
+        ..BorderSides::default()
+    };
+
+    assert!(BorderSides::default().is_empty());
+    assert!(!sides.is_empty());
+    assert_eq!(sides.resolve(&border), [&border, &border, &bottom, &border]);
+}
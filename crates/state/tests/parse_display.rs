@@ -19,6 +19,16 @@ fn parse_end_alignment() {
     assert_eq!(alignment, Ok(Alignment::End));
 }
 
+#[test]
+fn parse_space_alignments() {
+    assert_eq!(
+        Alignment::parse("space-between"),
+        Ok(Alignment::SpaceBetween)
+    );
+    assert_eq!(Alignment::parse("space-around"), Ok(Alignment::SpaceAround));
+    assert_eq!(Alignment::parse("space-evenly"), Ok(Alignment::SpaceEvenly));
+}
+
 #[test]
 fn parse_fallback_alignment() {
     let alignment = Alignment::parse("Hello, World!");
@@ -1,5 +1,6 @@
 use freya_engine::prelude::*;
-use freya_node_state::{GradientStop, LinearGradient, Parse};
+use freya_node_state::{ConicGradient, Fill, GradientStop, LinearGradient, Parse, RadialGradient};
+use torin::geometry::{Area, Point2D, Size2D};
 
 #[test]
 fn parse_basic_gradient() {
@@ -88,3 +89,109 @@ fn invalid_gradients() {
     assert_eq!(missing_color.is_err(), true);
     assert_eq!(missing_offset.is_err(), true);
 }
+
+#[test]
+fn parse_radial_gradient() {
+    assert_eq!(
+        RadialGradient::parse("radial-gradient(red 0%, rgb(0, 0, 255) 100%)"),
+        Ok(RadialGradient {
+            stops: vec![
+                GradientStop {
+                    color: Color::RED,
+                    offset: 0.0,
+                },
+                GradientStop {
+                    color: Color::BLUE,
+                    offset: 1.0,
+                }
+            ]
+        })
+    );
+}
+
+#[test]
+fn parse_conic_gradient() {
+    assert_eq!(
+        ConicGradient::parse("conic-gradient(red 0%, blue 100%)"),
+        Ok(ConicGradient {
+            angle: 0.0,
+            stops: vec![
+                GradientStop {
+                    color: Color::RED,
+                    offset: 0.0,
+                },
+                GradientStop {
+                    color: Color::BLUE,
+                    offset: 1.0,
+                }
+            ]
+        })
+    );
+}
+
+#[test]
+fn parse_conic_gradient_angle() {
+    assert_eq!(
+        ConicGradient::parse("conic-gradient(90deg, red 0%, blue 50%, red 100%)"),
+        Ok(ConicGradient {
+            angle: f32::to_radians(90.0),
+            stops: vec![
+                GradientStop {
+                    color: Color::RED,
+                    offset: 0.0,
+                },
+                GradientStop {
+                    color: Color::BLUE,
+                    offset: 0.5,
+                },
+                GradientStop {
+                    color: Color::RED,
+                    offset: 1.0,
+                }
+            ]
+        })
+    );
+}
+
+#[test]
+fn parse_gradient_fills() {
+    assert!(matches!(
+        Fill::parse("linear-gradient(red 0%, blue 100%)"),
+        Ok(Fill::LinearGradient(_))
+    ));
+    assert!(matches!(
+        Fill::parse("radial-gradient(red 0%, blue 100%)"),
+        Ok(Fill::RadialGradient(_))
+    ));
+    assert!(matches!(
+        Fill::parse("conic-gradient(45deg, red 0%, blue 100%)"),
+        Ok(Fill::ConicGradient(_))
+    ));
+}
+
+#[test]
+fn radial_gradient_radius() {
+    let square = Area::new(Point2D::new(10.0, 10.0), Size2D::new(100.0, 100.0));
+    let wide = Area::new(Point2D::new(10.0, 10.0), Size2D::new(400.0, 300.0));
+
+    // The last stop reaches the farthest corner
+    assert_eq!(RadialGradient::radius(square), 100.0_f32.hypot(100.0) / 2.0);
+    assert_eq!(RadialGradient::radius(wide), 250.0);
+}
+
+#[test]
+fn invalid_radial_and_conic_gradients() {
+    let radial_with_angle = RadialGradient::parse("radial-gradient(45deg, red 0%, blue 100%)");
+    let radial_missing_rparen = RadialGradient::parse("radial-gradient(red 0%, blue 100%");
+    let radial_missing_offset = RadialGradient::parse("radial-gradient(red, blue 100%)");
+    let conic_incorrect_name = ConicGradient::parse("linear-gradient(red 0%, blue 100%)");
+    let conic_extra_commas = ConicGradient::parse("conic-gradient(red 0%, blue 100%,)");
+    let conic_missing_color = ConicGradient::parse("conic-gradient(45deg, 0%, blue 100%)");
+
+    assert!(radial_with_angle.is_err());
+    assert!(radial_missing_rparen.is_err());
+    assert!(radial_missing_offset.is_err());
+    assert!(conic_incorrect_name.is_err());
+    assert!(conic_extra_commas.is_err());
+    assert!(conic_missing_color.is_err());
+}
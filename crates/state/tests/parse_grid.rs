@@ -0,0 +1,49 @@
+use freya_node_state::{parse_grid_tracks, Parse};
+use torin::{
+    geometry::Length,
+    grid::{GridPlacement, GridTrack},
+};
+
+#[test]
+fn parse_grid_tracks_list() {
+    let tracks = parse_grid_tracks("100 25% 1fr 2.5fr auto");
+    assert_eq!(
+        tracks,
+        Ok(vec![
+            GridTrack::Pixels(Length::new(100.0)),
+            GridTrack::Percentage(Length::new(25.0)),
+            GridTrack::Fraction(Length::new(1.0)),
+            GridTrack::Fraction(Length::new(2.5)),
+            GridTrack::Auto,
+        ])
+    );
+}
+
+#[test]
+fn parse_invalid_grid_track() {
+    let tracks = parse_grid_tracks("100 onefr");
+    assert!(tracks.is_err());
+}
+
+#[test]
+fn parse_grid_placement() {
+    assert_eq!(GridPlacement::parse("auto"), Ok(GridPlacement::default()));
+    assert_eq!(
+        GridPlacement::parse("2"),
+        Ok(GridPlacement::new(Some(1), 1))
+    );
+    assert_eq!(
+        GridPlacement::parse("1 / span 3"),
+        Ok(GridPlacement::new(Some(0), 3))
+    );
+    assert_eq!(
+        GridPlacement::parse("span 2"),
+        Ok(GridPlacement::new(None, 2))
+    );
+}
+
+#[test]
+fn parse_invalid_grid_placement() {
+    assert!(GridPlacement::parse("0").is_err());
+    assert!(GridPlacement::parse("1 / 3").is_err());
+}
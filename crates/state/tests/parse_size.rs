@@ -20,6 +20,18 @@ fn parse_auto_size() {
     assert_eq!(size, Ok(Size::Inner));
 }
 
+#[test]
+fn parse_flex_size() {
+    let size = Size::parse("flex(2)");
+    assert_eq!(size, Ok(Size::Flex(Length::new(2.0))));
+}
+
+#[test]
+fn parse_invalid_flex_size() {
+    let size = Size::parse("flex(two)");
+    assert!(size.is_err());
+}
+
 #[test]
 fn parse_calc_size() {
     let size = Size::parse("calc(90% - 5% * 123.6)");
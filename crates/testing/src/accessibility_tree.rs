@@ -0,0 +1,182 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt::{self, Display};
+
+use accesskit::{Action, Node, Role, TreeUpdate};
+use freya_core::types::AccessibilityId;
+use torin::geometry::Area;
+
+/// Node of the accessibility tree, as exposed to assistive technologies.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AccessibilityNodeSnapshot {
+    pub id: AccessibilityId,
+    pub role: Role,
+    pub name: Option<String>,
+    pub value: Option<String>,
+    pub bounds: Option<Area>,
+    pub focusable: bool,
+    pub focused: bool,
+    pub children: Vec<AccessibilityNodeSnapshot>,
+}
+
+impl AccessibilityNodeSnapshot {
+    fn new(
+        id: AccessibilityId,
+        nodes: &HashMap<AccessibilityId, &Node>,
+        children: &[AccessibilityId],
+        focus: AccessibilityId,
+    ) -> Option<Self> {
+        let node = nodes.get(&id)?;
+        let bounds = node.bounds().map(|bounds| {
+            Area::new(
+                (bounds.x0 as f32, bounds.y0 as f32).into(),
+                (bounds.width() as f32, bounds.height() as f32).into(),
+            )
+        });
+
+        Some(Self {
+            id,
+            role: node.role(),
+            name: node.name().map(str::to_string),
+            value: node.value().map(str::to_string),
+            bounds,
+            focusable: node.supports_action(Action::Focus),
+            focused: id == focus,
+            children: children
+                .iter()
+                .filter_map(|child_id| {
+                    let child = nodes.get(child_id)?;
+                    Self::new(*child_id, nodes, child.children(), focus)
+                })
+                .collect(),
+        })
+    }
+
+    /// Iterate over this Node and all its descendants, depth first.
+    pub fn iter(&self) -> impl Iterator<Item = &Self> {
+        let mut stack = vec![self];
+        std::iter::from_fn(move || {
+            let node = stack.pop()?;
+            stack.extend(node.children.iter().rev());
+            Some(node)
+        })
+    }
+
+    fn fmt_with_depth(&self, f: &mut fmt::Formatter<'_>, depth: usize) -> fmt::Result {
+        write!(f, "{:indent$}{:?}", "", self.role, indent = depth * 2)?;
+        if let Some(name) = &self.name {
+            write!(f, " name={name:?}")?;
+        }
+        if let Some(value) = &self.value {
+            write!(f, " value={value:?}")?;
+        }
+        if let Some(bounds) = &self.bounds {
+            write!(
+                f,
+                " bounds={},{},{},{}",
+                bounds.min_x(),
+                bounds.min_y(),
+                bounds.width(),
+                bounds.height()
+            )?;
+        }
+        if self.focusable {
+            write!(f, " focusable")?;
+        }
+        if self.focused {
+            write!(f, " focused")?;
+        }
+        writeln!(f)?;
+
+        for child in &self.children {
+            child.fmt_with_depth(f, depth + 1)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The accessibility tree of an app.
+///
+/// Its [Display] implementation prints one Node per line, indented by its depth, so it can be stored and compared as a text snapshot.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AccessibilityTree {
+    pub root: AccessibilityNodeSnapshot,
+    /// ID of the focused Node.
+    pub focus: AccessibilityId,
+}
+
+impl AccessibilityTree {
+    /// Build the tree out of an accessibility [TreeUpdate].
+    ///
+    /// Nodes are placed under their closest accessible ancestor, or under the root if they have none.
+    pub fn new(update: &TreeUpdate) -> Option<Self> {
+        let root_id = update.tree.as_ref()?.root;
+        let nodes = update
+            .nodes
+            .iter()
+            .map(|(id, node)| (*id, node))
+            .collect::<HashMap<AccessibilityId, &Node>>();
+
+        let nested = nodes
+            .iter()
+            .filter(|(id, _)| **id != root_id)
+            .flat_map(|(_, node)| node.children().iter().copied())
+            .collect::<HashSet<AccessibilityId>>();
+
+        let root_children = nodes
+            .get(&root_id)?
+            .children()
+            .iter()
+            .copied()
+            .filter(|id| !nested.contains(id))
+            .collect::<Vec<AccessibilityId>>();
+
+        let root = AccessibilityNodeSnapshot::new(root_id, &nodes, &root_children, update.focus)?;
+
+        Some(Self {
+            root,
+            focus: update.focus,
+        })
+    }
+
+    /// Iterate over all the Nodes, depth first.
+    pub fn iter(&self) -> impl Iterator<Item = &AccessibilityNodeSnapshot> {
+        self.root.iter()
+    }
+
+    /// Get the first Node that matches the given predicate.
+    pub fn find(
+        &self,
+        matcher: impl Fn(&AccessibilityNodeSnapshot) -> bool,
+    ) -> Option<&AccessibilityNodeSnapshot> {
+        self.iter().find(|node| matcher(node))
+    }
+
+    /// Get the first Node with the given role.
+    pub fn find_by_role(&self, role: Role) -> Option<&AccessibilityNodeSnapshot> {
+        self.find(|node| node.role == role)
+    }
+
+    /// Get the first Node with the given name.
+    pub fn find_by_name(&self, name: &str) -> Option<&AccessibilityNodeSnapshot> {
+        self.find(|node| node.name.as_deref() == Some(name))
+    }
+
+    /// Get the focused Node.
+    pub fn focused(&self) -> Option<&AccessibilityNodeSnapshot> {
+        self.find(|node| node.focused)
+    }
+
+    /// Get the focusable Nodes that have no name, which assistive technologies can't describe.
+    pub fn unnamed_focusable_nodes(&self) -> Vec<&AccessibilityNodeSnapshot> {
+        self.iter()
+            .filter(|node| node.focusable && node.name.is_none())
+            .collect()
+    }
+}
+
+impl Display for AccessibilityTree {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.root.fmt_with_depth(f, 0)
+    }
+}
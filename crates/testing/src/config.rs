@@ -1,5 +1,7 @@
 use std::time::Duration;
 
+use freya_common::PreferredTheme;
+use freya_core::prelude::{DOUBLE_CLICK_DISTANCE, DOUBLE_CLICK_INTERVAL};
 use torin::geometry::Size2D;
 
 /// Configuration for [`crate::test_handler::TestingHandler`].
@@ -8,6 +10,27 @@ pub struct TestingConfig {
     pub vdom_timeout: Duration,
     pub size: Size2D,
     pub event_loop_ticker: bool,
+    /// Freeze the tokio clock so time moves with [`crate::test_handler::TestingHandler::advance_time`].
+    ///
+    /// Note that, as any paused tokio clock, it still auto-advances to the next pending timer whenever the runtime
+    /// has no other work to do, e.g. while awaiting a `tokio::time::sleep`.
+    ///
+    /// Requires the `current_thread` tokio runtime, which is the default of `#[tokio::test]`, and a clock that is not
+    /// paused yet, so don't combine it with `#[tokio::test(start_paused = true)]`.
+    /// Launching the test panics otherwise.
+    pub virtual_clock: bool,
+    /// Folder where the reference images of [`crate::test_handler::TestingHandler::assert_snapshot`] are stored.
+    pub snapshots_path: &'static str,
+    /// Max difference allowed in each color channel of a pixel when comparing against a reference image.
+    pub snapshot_tolerance: u8,
+    /// Light/dark preference reported by the simulated platform, see [`crate::test_handler::TestingHandler::set_preferred_theme`].
+    pub preferred_theme: PreferredTheme,
+    /// Fonts used by the text that doesn't specify a `font_family`.
+    pub default_fonts: &'static [&'static str],
+    /// Maximum time between two clicks of a double click.
+    pub double_click_interval: Duration,
+    /// Maximum logical distance between two clicks of a double click.
+    pub double_click_distance: f64,
 }
 
 impl Default for TestingConfig {
@@ -16,6 +39,13 @@ impl Default for TestingConfig {
             vdom_timeout: Duration::from_millis(16),
             size: Size2D::from((500.0, 500.0)),
             event_loop_ticker: true,
+            virtual_clock: false,
+            snapshots_path: "tests/snapshots",
+            snapshot_tolerance: 0,
+            preferred_theme: PreferredTheme::Light,
+            default_fonts: &["Fira Sans"],
+            double_click_interval: DOUBLE_CLICK_INTERVAL,
+            double_click_distance: DOUBLE_CLICK_DISTANCE,
         }
     }
 }
@@ -34,4 +64,12 @@ impl TestingConfig {
     pub fn vdom_timeout(&self) -> Duration {
         self.vdom_timeout
     }
+
+    /// Get the default fonts.
+    pub fn default_fonts(&self) -> Vec<String> {
+        self.default_fonts
+            .iter()
+            .map(|font| font.to_string())
+            .collect()
+    }
 }
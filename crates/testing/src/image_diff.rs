@@ -0,0 +1,58 @@
+use image::{Rgba, RgbaImage};
+
+/// Color of the pixels that don't match in a diff image.
+const MISMATCH_COLOR: Rgba<u8> = Rgba([255, 0, 0, 255]);
+
+/// Differences between a reference image and a rendered frame.
+pub struct ImageDiff {
+    /// Number of pixels that differ more than the tolerance.
+    pub mismatched_pixels: u32,
+
+    /// The reference image faded, with the mismatched pixels highlighted in red.
+    pub image: RgbaImage,
+}
+
+impl ImageDiff {
+    /// Whether both images match.
+    pub fn is_match(&self) -> bool {
+        self.mismatched_pixels == 0
+    }
+}
+
+/// Compare two images of the same size, pixel by pixel.
+///
+/// A pixel is considered different if any of its channels differ more than `tolerance`.
+pub fn diff_images(reference: &RgbaImage, frame: &RgbaImage, tolerance: u8) -> ImageDiff {
+    assert_eq!(
+        reference.dimensions(),
+        frame.dimensions(),
+        "Only images of the same size can be compared."
+    );
+
+    let mut mismatched_pixels = 0;
+    let image = RgbaImage::from_fn(reference.width(), reference.height(), |x, y| {
+        let expected = reference.get_pixel(x, y);
+        let actual = frame.get_pixel(x, y);
+
+        let is_different = expected
+            .0
+            .iter()
+            .zip(actual.0)
+            .any(|(expected, actual)| expected.abs_diff(actual) > tolerance);
+
+        if is_different {
+            mismatched_pixels += 1;
+            MISMATCH_COLOR
+        } else {
+            let [r, g, b, _] = expected.0;
+            let luma = ((r as u32 + g as u32 + b as u32) / 3) as u8;
+            let faded = 255 - (255 - luma) / 4;
+            Rgba([faded, faded, faded, 255])
+        }
+    });
+
+    ImageDiff {
+        mismatched_pixels,
+        image,
+    }
+}
@@ -13,7 +13,7 @@ use tokio::sync::{broadcast, watch};
 use winit::window::CursorIcon;
 
 use crate::config::TestingConfig;
-use crate::test_handler::TestingHandler;
+use crate::test_handler::{pause_clock, TestingHandler};
 use crate::test_utils::TestUtils;
 
 /// Run a Component in a headless testing environment.
@@ -25,6 +25,14 @@ pub fn launch_test(root: AppComponent) -> TestingHandler {
 
 /// Run a Component in a headless testing environment
 pub fn launch_test_with_config(root: AppComponent, config: TestingConfig) -> TestingHandler {
+    if config.virtual_clock {
+        pause_clock();
+    }
+
+    let mut nodes_state = NodesState::default();
+    nodes_state.set_double_click_interval(config.double_click_interval);
+    nodes_state.set_double_click_distance(config.double_click_distance);
+
     let vdom = with_accessibility(root);
     let fdom = FreyaDOM::default();
     let sdom = SafeDOM::new(fdom);
@@ -32,14 +40,17 @@ pub fn launch_test_with_config(root: AppComponent, config: TestingConfig) -> Tes
     let (event_emitter, event_receiver) = unbounded_channel::<DomEvent>();
     let (platform_event_emitter, platform_event_receiver) = unbounded_channel::<EventMessage>();
     let (focus_sender, focus_receiver) = watch::channel(ACCESSIBILITY_ROOT_ID);
+    let (preferred_theme_sender, preferred_theme_receiver) = watch::channel(config.preferred_theme);
+    let font_mgr = FontMgr::default();
     let mut font_collection = FontCollection::new();
-    font_collection.set_dynamic_font_manager(FontMgr::default());
+    font_collection.set_dynamic_font_manager(font_mgr.clone());
 
     let mut handler = TestingHandler {
         vdom,
         events_queue: EventsQueue::new(),
-        nodes_state: NodesState::default(),
+        nodes_state,
         font_collection,
+        font_mgr,
         event_emitter,
         event_receiver,
         utils: TestUtils { sdom },
@@ -53,6 +64,8 @@ pub fn launch_test_with_config(root: AppComponent, config: TestingConfig) -> Tes
         cursor_icon: CursorIcon::default(),
         focus_sender,
         focus_receiver,
+        preferred_theme_sender,
+        preferred_theme_receiver,
     };
 
     handler.init_dom();
@@ -120,6 +120,125 @@
 //! }
 //! ```
 //!
+//! ## Queries example
+//!
+//! Rather than navigating the tree by indexes, which breaks as soon as a wrapper element is added, you can query the Nodes by their
+//! accessibility role, name or focus id, by their element tag, or by their text, exactly or with a substring or any custom predicate such as a regex.
+//!
+//! ```rust, no_run
+//! #[tokio::test]
+//! async fn queries_test() {
+//!     fn queries_component() -> Element {
+//!         rsx!(
+//!             Button {
+//!                 label { "Submit" }
+//!             }
+//!         )
+//!     }
+//!
+//!     let mut utils = launch_test(queries_component);
+//!     utils.wait_for_update().await;
+//!
+//!     let root = utils.root();
+//!     let button = root.get_by_role(Role::Button).unwrap();
+//!     assert!(button.get_by_text_containing("Sub").is_some());
+//!     assert_eq!(root.get_all_by_tag(TagName::Label).len(), 1);
+//! }
+//! ```
+//!
+//! ## Interactions example
+//!
+//! Instead of pushing the platform events by hand, you can interact with the Nodes like a user would.
+//! The helpers target the visible center of the Nodes and emit the same sequence of events as a real window, e.g a click is a `mouseover`, a `mousedown` and a `click`.
+//!
+//! ```rust, no_run
+//! #[tokio::test]
+//! async fn interactions_test() {
+//!     fn interactions_component() -> Element {
+//!         let mut value = use_signal(String::new);
+//!
+//!         rsx!(
+//!             Input {
+//!                 value: value.read().clone(),
+//!                 onchange: move |new_value| value.set(new_value),
+//!             }
+//!         )
+//!     }
+//!
+//!     let mut utils = launch_test(interactions_component);
+//!     utils.wait_for_update().await;
+//!
+//!     let input = utils.root().get(0);
+//!     utils.click(&input).await;
+//!     utils.type_text("Hello").await;
+//!     utils.press_key(Key::Backspace, Modifiers::default()).await;
+//! }
+//! ```
+//!
+//! ## Accessibility example
+//!
+//! The accessibility tree exposed to assistive technologies can be inspected to assert the roles, names, values, bounds and focus of the Nodes.
+//! It can also be printed as text to store it as a snapshot.
+//!
+//! ```rust, no_run
+//! #[tokio::test]
+//! async fn accessibility_test() {
+//!     fn accessibility_component() -> Element {
+//!         rsx!(
+//!             Button {
+//!                 label { "Submit" }
+//!             }
+//!         )
+//!     }
+//!
+//!     let mut utils = launch_test(accessibility_component);
+//!     utils.wait_for_update().await;
+//!
+//!     let tree = utils.accessibility_tree();
+//!     assert!(tree.find_by_role(Role::Button).is_some());
+//!
+//!     // Focusable Nodes without a name can't be described to the users
+//!     for node in tree.unnamed_focusable_nodes() {
+//!         println!("{:?} has no name", node.role);
+//!     }
+//! }
+//! ```
+//!
+//! ## Virtual clock example
+//!
+//! Animations and timers use the real clock by default. Enable `TestingConfig::virtual_clock` to freeze it,
+//! so time moves when you call `advance_time`, and the animations can be asserted frame by frame.
+//! Just like any paused tokio clock, it also auto-advances to the next pending timer when the runtime has nothing else to do.
+//! It requires the `current_thread` tokio runtime, which is the default of `#[tokio::test]`.
+//!
+//! ```rust, no_run
+//! #[tokio::test]
+//! async fn animation_test() {
+//!     fn animation_component() -> Element {
+//!         let animation = use_animation(|ctx| {
+//!             ctx.auto_start(true);
+//!             ctx.with(AnimNum::new(0., 100.).time(100).function(Function::Linear))
+//!         });
+//!
+//!         let width = animation.get().read().as_f32();
+//!
+//!         rsx!(rect { width: "{width}" })
+//!     }
+//!
+//!     let mut utils = launch_test_with_config(
+//!         animation_component,
+//!         TestingConfig {
+//!             virtual_clock: true,
+//!             ..TestingConfig::default()
+//!         },
+//!     );
+//!     utils.wait_for_update().await;
+//!
+//!     utils.advance_time(Duration::from_millis(50)).await;
+//!     assert_eq!(utils.root().get(0).area().unwrap().width(), 50.0);
+//! }
+//! ```
+//!
 //! ## Configuration example
 //!
 //! The `launch_test` comes with a default configuration, but you can pass your own config with the `launch_test_with_config` function.
@@ -152,8 +271,120 @@
 //!     assert_eq!(label_text.text(), Some("Hello World!"));
 //! }
 //! ````
+//!
+//! ## Layout snapshot example
+//!
+//! You can compare the layout of your component against a snapshot stored in a file, the first time the test runs the file will be created.
+//! Set the `FREYA_UPDATE_SNAPSHOTS` environment variable to update the stored snapshots after an intended change.
+//!
+//! ```rust, no_run
+//! #[tokio::test]
+//! async fn test() {
+//!     fn our_component() -> Element {
+//!         rsx!(
+//!             rect {
+//!                 width: "50%",
+//!                 height: "100",
+//!             }
+//!         )
+//!     }
+//!
+//!     let mut utils = launch_test(our_component);
+//!
+//!     utils.assert_layout_snapshot("tests/snapshots/our_component.layout");
+//! }
+//! ```
+//!
+//! ## Rendering example
+//!
+//! You can also render your component into an image, this uses a CPU surface so it works on machines without a GPU.
+//!
+//! ```rust, no_run
+//! #[tokio::test]
+//! async fn test() {
+//!     fn our_component() -> Element {
+//!         rsx!(
+//!             rect {
+//!                 width: "100%",
+//!                 height: "100%",
+//!                 background: "red",
+//!             }
+//!         )
+//!     }
+//!
+//!     let mut utils = launch_test(our_component);
+//!
+//!     let image = utils.render_to_image();
+//!     assert_eq!(image.get_pixel(0, 0).0, [255, 0, 0, 255]);
+//!
+//!     // Or save it as a PNG file
+//!     utils.render_to_png("tests/images/our_component.png");
+//! }
+//! ```
+//!
+//! ## Visual regression example
+//!
+//! Compare the rendered frame against a reference image stored in `tests/snapshots/<name>.png`, the first time the test runs the image will be created.
+//! When the frame doesn't match, a `<name>.diff.png` image highlighting the different pixels in red is written next to it.
+//! Set the `FREYA_UPDATE_SNAPSHOTS` environment variable to overwrite the stale reference images.
+//!
+//! The folder and the per-pixel tolerance can be changed with `TestingConfig::snapshots_path` and `TestingConfig::snapshot_tolerance`.
+//!
+//! ```rust, no_run
+//! #[tokio::test]
+//! async fn test() {
+//!     fn our_component() -> Element {
+//!         rsx!(
+//!             ThemeProvider {
+//!                 theme: DARK_THEME,
+//!                 Button {
+//!                     label { "Click me" }
+//!                 }
+//!             }
+//!         )
+//!     }
+//!
+//!     let mut utils = launch_test(our_component);
+//!     utils.wait_for_update().await;
+//!
+//!     utils.assert_snapshot("button_dark");
+//! }
+//! ```
+//!
+//! ## Replay example
+//!
+//! Record the events of the real app with `LaunchConfigBuilder::with_events_recording` or the `FREYA_RECORD_EVENTS` environment variable,
+//! and replay them headlessly to reproduce a crash or a visual bug.
+//!
+//! ```rust, no_run
+//! #[tokio::test]
+//! async fn test() {
+//!     fn our_component() -> Element {
+//!         rsx!(
+//!             Button {
+//!                 label { "Click me" }
+//!             }
+//!         )
+//!     }
+//!
+//!     let mut utils = launch_test_with_config(
+//!         our_component,
+//!         TestingConfig {
+//!             virtual_clock: true,
+//!             ..TestingConfig::default()
+//!         },
+//!     );
+//!     utils.wait_for_update().await;
+//!
+//!     utils.replay_file("tests/recordings/click.txt").await;
+//!
+//!     utils.assert_snapshot("button_clicked");
+//! }
+//! ```
 
+pub mod accessibility_tree;
 pub mod config;
+pub mod image_diff;
 pub mod launch;
 pub mod test_handler;
 pub mod test_node;
@@ -162,11 +393,16 @@ pub mod test_utils;
 const SCALE_FACTOR: f64 = 1.0;
 
 pub mod prelude {
+    pub use crate::accessibility_tree::*;
     pub use crate::config::*;
+    pub use crate::image_diff::*;
     pub use crate::launch::*;
     pub use crate::test_handler::*;
     pub use crate::test_node::*;
     pub use crate::test_utils::*;
+    pub use accesskit::Role;
+    pub use freya_common::PreferredTheme;
     pub use freya_core::prelude::*;
+    pub use freya_native_core::tags::TagName;
     pub use freya_node_state::*;
 }
@@ -1,19 +1,32 @@
+use std::path::Path;
 use std::sync::{Arc, Mutex};
 use std::time::Duration;
 
 use dioxus_core::VirtualDom;
-use freya_common::EventMessage;
+use freya_common::{EventMessage, PreferredTheme};
 use freya_core::prelude::*;
-use freya_engine::prelude::FontCollection;
+use freya_elements::events::keyboard::{Code, Key, Modifiers};
+use freya_engine::prelude::{
+    surfaces, AlphaType, Color, ColorType, EncodedImageFormat, FontCollection, FontMgr, ImageInfo,
+    Surface,
+};
 use freya_hooks::PlatformInformation;
 use freya_native_core::dioxus::NodeImmutableDioxusExt;
+use freya_renderer::render_dom;
+use image::RgbaImage;
+use tokio::runtime::{Handle, RuntimeFlavor};
 use tokio::sync::broadcast;
 use tokio::sync::mpsc::{UnboundedReceiver, UnboundedSender};
 use tokio::time::{interval, timeout};
+use torin::geometry::CursorPoint;
 use torin::geometry::{Area, Size2D};
+use torin::snapshot::LayoutSnapshot;
+use winit::event::MouseButton;
 use winit::window::CursorIcon;
 
+use crate::accessibility_tree::AccessibilityTree;
 use crate::config::TestingConfig;
+use crate::image_diff::diff_images;
 use crate::test_node::TestNode;
 use crate::test_utils::TestUtils;
 use crate::SCALE_FACTOR;
@@ -30,7 +43,10 @@ pub struct TestingHandler {
     pub(crate) nodes_state: NodesState,
     pub(crate) focus_sender: FocusSender,
     pub(crate) focus_receiver: FocusReceiver,
+    pub(crate) preferred_theme_sender: PreferredThemeSender,
+    pub(crate) preferred_theme_receiver: PreferredThemeReceiver,
     pub(crate) font_collection: FontCollection,
+    pub(crate) font_mgr: FontMgr,
     pub(crate) accessibility_manager: SharedAccessibilityManager,
     pub(crate) config: TestingConfig,
     pub(crate) ticker_sender: broadcast::Sender<()>,
@@ -59,6 +75,8 @@ impl TestingHandler {
             .insert_any_root_context(Box::new(self.platform_event_emitter.clone()));
         self.vdom
             .insert_any_root_context(Box::new(self.focus_receiver.clone()));
+        self.vdom
+            .insert_any_root_context(Box::new(self.preferred_theme_receiver.clone()));
         self.vdom
             .insert_any_root_context(Box::new(Arc::new(self.ticker_sender.subscribe())));
         self.vdom
@@ -71,7 +89,8 @@ impl TestingHandler {
     pub async fn wait_for_update(&mut self) -> (bool, bool) {
         self.wait_for_work(self.config.size());
 
-        let mut ticker = if self.config.event_loop_ticker {
+        // With a virtual clock the frames are only ticked by the handler itself
+        let mut ticker = if self.config.event_loop_ticker && !self.config.virtual_clock {
             Some(interval(Duration::from_millis(16)))
         } else {
             None
@@ -92,12 +111,13 @@ impl TestingHandler {
                         if let Some(ticker) = ticker.as_mut() {
                             ticker.tick().await;
                             self.ticker_sender.send(()).unwrap();
-                            timeout(self.config.vdom_timeout(), self.vdom.wait_for_work())
+                            timeout(self.vdom_timeout(), self.vdom.wait_for_work())
                                 .await
                                 .ok();
                         }
                     }
                     EventMessage::FocusAccessibilityNode(node_id) => {
+                        let blurred_id = self.focus_id();
                         let tree = self
                             .accessibility_manager
                             .lock()
@@ -109,8 +129,10 @@ impl TestingHandler {
                                 .send(tree.focus)
                                 .expect("Failed to focus the Node.");
                         }
+                        self.emit_focus_events(blurred_id);
                     }
                     EventMessage::FocusNextAccessibilityNode => {
+                        let blurred_id = self.focus_id();
                         let tree = self
                             .accessibility_manager
                             .lock()
@@ -119,8 +141,10 @@ impl TestingHandler {
                         self.focus_sender
                             .send(tree.focus)
                             .expect("Failed to focus the Node.");
+                        self.emit_focus_events(blurred_id);
                     }
                     EventMessage::FocusPrevAccessibilityNode => {
+                        let blurred_id = self.focus_id();
                         let tree = self
                             .accessibility_manager
                             .lock()
@@ -129,6 +153,7 @@ impl TestingHandler {
                         self.focus_sender
                             .send(tree.focus)
                             .expect("Failed to focus the Node.");
+                        self.emit_focus_events(blurred_id);
                     }
                     EventMessage::SetCursorIcon(icon) => {
                         self.cursor_icon = icon;
@@ -136,11 +161,18 @@ impl TestingHandler {
                     EventMessage::SetWindowSize(size) => {
                         self.config.size = size;
                     }
+                    EventMessage::PreferredThemeChanged(preferred_theme) => {
+                        self.set_preferred_theme(preferred_theme);
+                    }
                     _ => {}
                 }
             }
 
             if let Ok(ev) = vdom_ev {
+                // A previous listener might have stopped this event from reaching the rest
+                if ev.data.is_propagation_stopped() {
+                    continue;
+                }
                 let data = ev.data.any();
                 let fdom = self.utils.sdom().get();
                 let rdom = fdom.rdom();
@@ -157,7 +189,7 @@ impl TestingHandler {
             }
         }
 
-        timeout(self.config.vdom_timeout(), self.vdom.wait_for_work())
+        timeout(self.vdom_timeout(), self.vdom.wait_for_work())
             .await
             .ok();
 
@@ -174,6 +206,31 @@ impl TestingHandler {
         (must_repaint, must_relayout)
     }
 
+    /// Get how long to wait for the VirtualDOM, which is no time with a virtual clock so it never moves by itself.
+    fn vdom_timeout(&self) -> Duration {
+        if self.config.virtual_clock {
+            Duration::ZERO
+        } else {
+            self.config.vdom_timeout()
+        }
+    }
+
+    /// Move the clock forward by the given duration and process a new frame.
+    ///
+    /// Animations, tickers and tokio timers will all observe the elapsed time, so they can be asserted frame by frame.
+    /// The clock is frozen the first time this is called, unless [`TestingConfig::virtual_clock`] was already enabled,
+    /// see [`TestingConfig::virtual_clock`] for its requirements.
+    pub async fn advance_time(&mut self, duration: Duration) {
+        if !self.config.virtual_clock {
+            pause_clock();
+            self.config.virtual_clock = true;
+        }
+
+        tokio::time::advance(duration).await;
+        self.ticker_sender.send(()).ok();
+        self.wait_for_update().await;
+    }
+
     /// Wait for layout and events to be processed
     pub fn wait_for_work(&mut self, size: Size2D) {
         // Clear cached results
@@ -188,16 +245,15 @@ impl TestingHandler {
             },
             &mut self.font_collection,
             SCALE_FACTOR as f32,
-            &["Fira Sans".to_string()],
+            &self.config.default_fonts(),
         );
 
         let dom = &self.utils.sdom().get_mut();
 
-        process_accessibility(
-            &dom.layout(),
-            dom.rdom(),
-            &mut self.accessibility_manager.lock().unwrap(),
-        );
+        let mut accessibility_manager = self.accessibility_manager.lock().unwrap();
+        accessibility_manager.clear();
+        process_accessibility(&dom.layout(), dom.rdom(), &mut accessibility_manager);
+        drop(accessibility_manager);
 
         process_events(
             dom,
@@ -213,6 +269,129 @@ impl TestingHandler {
         self.events_queue.push(event);
     }
 
+    /// Push an event and wait for the app to process it.
+    async fn send_event(&mut self, event: PlatformEvent) {
+        self.push_event(event);
+        self.wait_for_update().await;
+    }
+
+    /// Replay the events of a recording made with `LaunchConfigBuilder::with_events_recording`.
+    ///
+    /// The canvas is resized as it was in the recording, and the time between events is awaited,
+    /// which is instant when [`TestingConfig::virtual_clock`] is enabled.
+    pub async fn replay(&mut self, recording: &EventsRecording) {
+        let scale_factor = recording.scale_factor as f32;
+        self.resize(recording.window_size / scale_factor);
+        self.wait_for_update().await;
+
+        let mut time = Duration::ZERO;
+        for entry in &recording.entries {
+            let elapsed = entry.time.saturating_sub(time);
+            time = entry.time;
+            if self.config.virtual_clock {
+                self.advance_time(elapsed).await;
+            } else {
+                tokio::time::sleep(elapsed).await;
+            }
+
+            match &entry.event {
+                RecordedEvent::Resize(size) => {
+                    self.resize(*size / scale_factor);
+                    self.wait_for_update().await;
+                }
+                RecordedEvent::Platform(event) => {
+                    self.send_event(to_logical_event(event, recording.scale_factor))
+                        .await;
+                }
+            }
+        }
+    }
+
+    /// Replay the events of a recording file, panicking if it can't be read or parsed.
+    pub async fn replay_file(&mut self, path: impl AsRef<Path>) {
+        let path = path.as_ref();
+        let content = std::fs::read_to_string(path)
+            .unwrap_or_else(|err| panic!("Failed to read {}: {err}", path.display()));
+        let recording = EventsRecording::parse(&content).unwrap_or_else(|err| {
+            panic!("Failed to parse line {} of {}.", err.line, path.display())
+        });
+        self.replay(&recording).await;
+    }
+
+    /// Get the point where to interact with the given Node, panicking if it's not visible.
+    #[track_caller]
+    fn pointer_target(node: &TestNode) -> CursorPoint {
+        node.visible_center()
+            .expect("The Node is not visible, so it can't be interacted with.")
+    }
+
+    /// Move the cursor over the visible center of the given Node.
+    pub async fn hover(&mut self, node: &TestNode) {
+        let cursor = Self::pointer_target(node);
+        self.send_event(PlatformEvent::Mouse {
+            name: EventName::MouseOver,
+            cursor,
+            button: None,
+        })
+        .await;
+    }
+
+    /// Click the visible center of the given Node with the left button.
+    ///
+    /// Just like a user would, the cursor is moved over the Node, then pressed and released.
+    /// So the Node will receive `mouseover`, `mousedown`, `pointerdown`, `pointerup` and `click` events.
+    pub async fn click(&mut self, node: &TestNode) {
+        let cursor = Self::pointer_target(node);
+        self.hover(node).await;
+        self.send_event(PlatformEvent::Mouse {
+            name: EventName::MouseDown,
+            cursor,
+            button: Some(MouseButton::Left),
+        })
+        .await;
+        self.send_event(PlatformEvent::Mouse {
+            name: EventName::Click,
+            cursor,
+            button: Some(MouseButton::Left),
+        })
+        .await;
+    }
+
+    /// Scroll the wheel over the visible center of the given Node.
+    ///
+    /// The `delta` is given just like the platform does, so negative values scroll down or to the right.
+    pub async fn scroll_by(&mut self, node: &TestNode, delta: impl Into<CursorPoint>) {
+        let cursor = Self::pointer_target(node);
+        self.hover(node).await;
+        self.send_event(PlatformEvent::Wheel {
+            name: EventName::Wheel,
+            scroll: delta.into(),
+            cursor,
+        })
+        .await;
+    }
+
+    /// Press and release the given key.
+    pub async fn press_key(&mut self, key: Key, modifiers: Modifiers) {
+        for name in [EventName::KeyDown, EventName::KeyUp] {
+            self.send_event(PlatformEvent::Keyboard {
+                name,
+                key: key.clone(),
+                code: Code::Unidentified,
+                modifiers,
+            })
+            .await;
+        }
+    }
+
+    /// Type the given text into the focused Node, one character at a time.
+    pub async fn type_text(&mut self, text: &str) {
+        for character in text.chars() {
+            self.press_key(Key::Character(character.to_string()), Modifiers::default())
+                .await;
+        }
+    }
+
     /// Get the root node
     pub fn root(&mut self) -> TestNode {
         let root_id = {
@@ -233,12 +412,42 @@ impl TestingHandler {
         self.accessibility_manager.lock().unwrap().focused_id
     }
 
+    /// Notify the Nodes that lost or got the focus.
+    fn emit_focus_events(&self, blurred_id: AccessibilityId) {
+        emit_focus_events(
+            &self.utils.sdom().get(),
+            &self.event_emitter,
+            blurred_id,
+            self.focus_id(),
+        );
+    }
+
     /// Resize the simulated canvas.
     pub fn resize(&mut self, size: Size2D) {
         self.config.size = size;
         self.platform_information.lock().unwrap().window_size = size;
     }
 
+    /// Simulate a change of the light/dark preference of the platform.
+    pub fn set_preferred_theme(&mut self, preferred_theme: PreferredTheme) {
+        self.config.preferred_theme = preferred_theme;
+        self.preferred_theme_sender.send_if_modified(|current| {
+            let changed = *current != preferred_theme;
+            *current = preferred_theme;
+            changed
+        });
+    }
+
+    /// Get the current [AccessibilityTree].
+    pub fn accessibility_tree(&self) -> AccessibilityTree {
+        let update = self
+            .accessibility_manager
+            .lock()
+            .unwrap()
+            .process(ACCESSIBILITY_ROOT_ID, "Freya");
+        AccessibilityTree::new(&update).expect("The accessibility tree has no root.")
+    }
+
     /// Get the current [CursorIcon].
     pub fn cursor_icon(&self) -> CursorIcon {
         self.cursor_icon
@@ -248,4 +457,186 @@ impl TestingHandler {
     pub fn sdom(&self) -> &SafeDOM {
         self.utils.sdom()
     }
+
+    /// Take a [LayoutSnapshot] of the whole app.
+    pub fn layout_snapshot(&self) -> LayoutSnapshot {
+        let fdom = self.utils.sdom().get();
+        let rdom = fdom.rdom();
+        let mut dom_adapter = DioxusDOMAdapter::new_with_cache(rdom);
+        let layout = fdom.layout();
+        layout.snapshot(rdom.root_id(), &mut dom_adapter)
+    }
+
+    /// Assert that the layout of the app matches the snapshot stored in the given file.
+    ///
+    /// The file is created when it doesn't exist yet, and overwritten when
+    /// the `FREYA_UPDATE_SNAPSHOTS` environment variable is set.
+    pub fn assert_layout_snapshot(&self, path: impl AsRef<Path>) {
+        let path = path.as_ref();
+        let snapshot = self.layout_snapshot();
+
+        if !path.exists() || std::env::var_os("FREYA_UPDATE_SNAPSHOTS").is_some() {
+            if let Some(parent) = path.parent() {
+                std::fs::create_dir_all(parent).expect("Failed to create the snapshots folder.");
+            }
+            std::fs::write(path, snapshot.to_string()).expect("Failed to write the snapshot.");
+            return;
+        }
+
+        let stored = std::fs::read_to_string(path).expect("Failed to read the snapshot.");
+        let stored = LayoutSnapshot::parse(&stored).unwrap_or_else(|err| {
+            panic!(
+                "Failed to parse line {} of the snapshot {}.",
+                err.line,
+                path.display()
+            )
+        });
+
+        let diff = stored.diff(&snapshot);
+        if !diff.is_empty() {
+            let diff = diff
+                .iter()
+                .map(|diff| diff.to_string())
+                .collect::<Vec<String>>()
+                .join("\n");
+            panic!(
+                "The layout doesn't match the snapshot {}:\n{diff}",
+                path.display()
+            );
+        }
+    }
+
+    /// Render the current frame of the app into a surface.
+    ///
+    /// It uses a CPU raster surface of the configured [`TestingConfig::size`], so no GPU is required.
+    fn render_surface(&mut self) -> Surface {
+        let size = self.config.size();
+        let mut surface = surfaces::raster_n32_premul((size.width as i32, size.height as i32))
+            .expect("Failed to create the raster surface.");
+
+        let canvas = surface.canvas();
+        canvas.clear(Color::WHITE);
+
+        let fdom = self.utils.sdom().get();
+        render_dom(
+            canvas,
+            &fdom,
+            &mut self.font_collection,
+            &self.font_mgr,
+            &self.config.default_fonts(),
+            &None,
+        );
+
+        surface
+    }
+
+    /// Render the current frame of the app into an image.
+    pub fn render_to_image(&mut self) -> RgbaImage {
+        let size = self.config.size();
+        let (width, height) = (size.width as i32, size.height as i32);
+        let mut surface = self.render_surface();
+
+        let info = ImageInfo::new(
+            (width, height),
+            ColorType::RGBA8888,
+            AlphaType::Unpremul,
+            None,
+        );
+        let row_bytes = width as usize * 4;
+        let mut pixels = vec![0; row_bytes * height as usize];
+        assert!(
+            surface.read_pixels(&info, &mut pixels, row_bytes, (0, 0)),
+            "Failed to read the rendered frame."
+        );
+
+        RgbaImage::from_raw(width as u32, height as u32, pixels)
+            .expect("Failed to create the image of the rendered frame.")
+    }
+
+    /// Render the current frame of the app into a PNG file in the given path.
+    pub fn render_to_png(&mut self, path: impl AsRef<Path>) {
+        let path = path.as_ref();
+        let data = self
+            .render_surface()
+            .image_snapshot()
+            .encode(None, EncodedImageFormat::PNG, None)
+            .expect("Failed to encode the rendered frame.");
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).expect("Failed to create the images folder.");
+        }
+        std::fs::write(path, data.as_bytes()).expect("Failed to write the rendered frame.");
+    }
+
+    /// Assert that the current frame of the app matches the reference image with the given name.
+    ///
+    /// The reference image is stored in `<TestingConfig::snapshots_path>/<name>.png`, it is created
+    /// when it doesn't exist yet, and overwritten when the `FREYA_UPDATE_SNAPSHOTS` environment variable is set.
+    /// On mismatch, an image highlighting the different pixels is written to `<name>.diff.png`.
+    pub fn assert_snapshot(&mut self, name: &str) {
+        let folder = Path::new(self.config.snapshots_path);
+        let path = folder.join(format!("{name}.png"));
+        let diff_path = folder.join(format!("{name}.diff.png"));
+
+        if !path.exists() || std::env::var_os("FREYA_UPDATE_SNAPSHOTS").is_some() {
+            self.render_to_png(&path);
+            std::fs::remove_file(&diff_path).ok();
+            return;
+        }
+
+        let frame = self.render_to_image();
+        let reference = image::open(&path)
+            .expect("Failed to read the reference image.")
+            .to_rgba8();
+
+        if reference.dimensions() != frame.dimensions() {
+            panic!(
+                "The frame size {:?} doesn't match the size {:?} of the reference image {}.",
+                frame.dimensions(),
+                reference.dimensions(),
+                path.display()
+            );
+        }
+
+        let diff = diff_images(&reference, &frame, self.config.snapshot_tolerance);
+        if diff.is_match() {
+            std::fs::remove_file(&diff_path).ok();
+        } else {
+            diff.image
+                .save(&diff_path)
+                .expect("Failed to write the diff image.");
+            panic!(
+                "{} pixels don't match the reference image {}, see {}.",
+                diff.mismatched_pixels,
+                path.display(),
+                diff_path.display()
+            );
+        }
+    }
+}
+
+/// Freeze the tokio clock of the current runtime.
+///
+/// # Panics
+///
+/// When the runtime is not a `current_thread` one or its clock is already frozen.
+pub(crate) fn pause_clock() {
+    assert_eq!(
+        Handle::current().runtime_flavor(),
+        RuntimeFlavor::CurrentThread,
+        "The virtual clock requires the `current_thread` tokio runtime, which is the default of `#[tokio::test]`."
+    );
+    tokio::time::pause();
+}
+
+/// Convert the physical coordinates of a recorded event into logical ones, as the tests run with a scale factor of 1.
+fn to_logical_event(event: &PlatformEvent, scale_factor: f64) -> PlatformEvent {
+    let mut event = event.clone();
+    match &mut event {
+        PlatformEvent::Mouse { cursor, .. }
+        | PlatformEvent::Wheel { cursor, .. }
+        | PlatformEvent::File { cursor, .. } => *cursor = *cursor / scale_factor,
+        PlatformEvent::Touch { location, .. } => *location = *location / scale_factor,
+        PlatformEvent::Keyboard { .. } => {}
+    }
+    event
 }
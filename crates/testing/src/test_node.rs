@@ -1,8 +1,14 @@
+use accesskit::Role;
+use freya_core::dom::DioxusNode;
 use freya_core::node::NodeState;
+use freya_core::types::AccessibilityId;
 use freya_native_core::NodeId;
-use freya_native_core::{node::NodeType, real_dom::NodeImmutable};
-use freya_node_state::{CustomAttributeValues, Style, ViewportState};
-use torin::{geometry::Area, prelude::LayoutNode};
+use freya_native_core::{node::NodeType, real_dom::NodeImmutable, tags::TagName};
+use freya_node_state::{AccessibilityNodeState, CustomAttributeValues, Style, ViewportState};
+use torin::{
+    geometry::{Area, CursorPoint},
+    prelude::LayoutNode,
+};
 
 use crate::test_utils::TestUtils;
 
@@ -32,6 +38,11 @@ impl TestNode {
         Some(child)
     }
 
+    /// Get the Node ID
+    pub fn id(&self) -> NodeId {
+        self.node_id
+    }
+
     /// Get the Node text
     pub fn text(&self) -> Option<&str> {
         self.node_type.text()
@@ -114,6 +125,27 @@ impl TestNode {
         true
     }
 
+    /// Get the center of the visible part of the Node, which is where a user would point at it.
+    pub fn visible_center(&self) -> Option<CursorPoint> {
+        let mut area = self.area()?;
+
+        let sdom = self.utils().sdom();
+        let fdom = sdom.get();
+        let dom = fdom.rdom();
+        let node = dom.get(self.node_id).unwrap();
+        let node_viewports = node.get::<ViewportState>().unwrap();
+
+        let layout = fdom.layout();
+
+        // Clip the area with all its parent's viewports
+        for viewport_id in &node_viewports.viewports {
+            let viewport = layout.get(*viewport_id).unwrap().visible_area();
+            area = area.intersection(&viewport)?;
+        }
+
+        Some(area.center().cast())
+    }
+
     /// Get the IDs of this Node children.
     pub fn children_ids(&self) -> Vec<NodeId> {
         self.children_ids.clone()
@@ -134,17 +166,92 @@ impl TestNode {
         self.node_type.is_placeholder()
     }
 
+    /// Get the first Node inside this Node, including itself, that matches the given predicate.
+    pub fn get_by(&self, matcher: impl Fn(&DioxusNode) -> bool) -> Option<Self> {
+        self.get_all_by(matcher).into_iter().next()
+    }
+
+    /// Get all the Nodes inside this Node, including itself, that match the given predicate.
+    pub fn get_all_by(&self, matcher: impl Fn(&DioxusNode) -> bool) -> Vec<Self> {
+        self.utils()
+            .get_node_matching_inside_id(self.node_id, matcher)
+    }
+
     /// Get a Node by a matching text.
     pub fn get_by_text(&self, matching_text: &str) -> Option<Self> {
-        self.utils()
-            .get_node_matching_inside_id(self.node_id, |node| {
-                if let NodeType::Text(text) = &*node.node_type() {
-                    matching_text == text
-                } else {
-                    false
-                }
-            })
-            .first()
-            .cloned()
+        self.get_by_text_matching(|text| text == matching_text)
+    }
+
+    /// Get a Node whose text contains the given text.
+    pub fn get_by_text_containing(&self, matching_text: &str) -> Option<Self> {
+        self.get_by_text_matching(|text| text.contains(matching_text))
+    }
+
+    /// Get a Node whose text matches the given predicate, e.g a regex.
+    pub fn get_by_text_matching(&self, matcher: impl Fn(&str) -> bool) -> Option<Self> {
+        self.get_all_by_text_matching(matcher).into_iter().next()
+    }
+
+    /// Get all the Nodes whose text matches the given predicate, e.g a regex.
+    pub fn get_all_by_text_matching(&self, matcher: impl Fn(&str) -> bool) -> Vec<Self> {
+        self.get_all_by(|node| {
+            if let NodeType::Text(text) = &*node.node_type() {
+                matcher(text)
+            } else {
+                false
+            }
+        })
+    }
+
+    /// Get a Node by its element tag.
+    pub fn get_by_tag(&self, tag: TagName) -> Option<Self> {
+        self.get_all_by_tag(tag).into_iter().next()
+    }
+
+    /// Get all the Nodes with the given element tag.
+    pub fn get_all_by_tag(&self, tag: TagName) -> Vec<Self> {
+        self.get_all_by(|node| node.node_type().tag() == Some(&tag))
+    }
+
+    /// Get a Node by its accessibility role.
+    pub fn get_by_role(&self, role: Role) -> Option<Self> {
+        self.get_all_by_role(role).into_iter().next()
+    }
+
+    /// Get all the Nodes with the given accessibility role.
+    pub fn get_all_by_role(&self, role: Role) -> Vec<Self> {
+        self.get_all_by(|node| {
+            node.get::<AccessibilityNodeState>()
+                .is_some_and(|state| state.role == Some(role))
+        })
+    }
+
+    /// Get a Node by its accessibility name.
+    pub fn get_by_name(&self, name: &str) -> Option<Self> {
+        self.get_by(|node| {
+            node.get::<AccessibilityNodeState>()
+                .is_some_and(|state| state.name.as_deref() == Some(name))
+        })
+    }
+
+    /// Get a Node by its [AccessibilityId].
+    pub fn get_by_focus_id(&self, focus_id: AccessibilityId) -> Option<Self> {
+        self.get_by(|node| {
+            node.get::<AccessibilityNodeState>()
+                .is_some_and(|state| state.accessibility_id == Some(focus_id))
+        })
+    }
+
+    /// Get the accessibility state of the Node.
+    pub fn accessibility(&self) -> AccessibilityNodeState {
+        self.utils
+            .sdom
+            .get()
+            .rdom()
+            .get(self.node_id)
+            .unwrap()
+            .get::<AccessibilityNodeState>()
+            .unwrap()
+            .clone()
     }
 }
@@ -40,7 +40,7 @@ impl TestUtils {
         }
     }
 
-    /// Get a list of Nodes matching the text
+    /// Get a list of the Nodes inside the given Node, including itself, that match the given predicate
     pub fn get_node_matching_inside_id(
         &self,
         node_id: NodeId,
@@ -74,7 +74,7 @@ impl TestUtils {
                 let node_type = node.node_type().clone();
 
                 nodes.push(TestNode {
-                    node_id,
+                    node_id: node.id(),
                     utils,
                     children_ids,
                     height,
@@ -137,3 +137,330 @@ async fn match_by_text() {
         Some("Hello, Rust!")
     );
 }
+
+#[tokio::test]
+async fn queries() {
+    fn app() -> Element {
+        let focus = use_focus();
+
+        rsx!(
+            rect {
+                rect {
+                    role: "button",
+                    name: "Submit",
+                    focus_id: focus.attribute(),
+                    label {
+                        "Submit the form"
+                    }
+                }
+            }
+            rect {
+                role: "button",
+                label {
+                    "Cancel 42"
+                }
+            }
+            image {}
+        )
+    }
+
+    let mut utils = launch_test(app);
+    utils.wait_for_update().await;
+    let root = utils.root();
+
+    let submit = root.get_by_name("Submit").unwrap();
+    assert_eq!(submit.get(0).get(0).text(), Some("Submit the form"));
+    assert_eq!(submit.accessibility().role, Some(Role::Button));
+    let focus_id = submit.accessibility().accessibility_id.unwrap();
+    assert_eq!(root.get_by_focus_id(focus_id).unwrap().id(), submit.id());
+
+    let buttons = root.get_all_by_role(Role::Button);
+    assert_eq!(buttons.len(), 2);
+    assert_eq!(buttons[0].id(), submit.id());
+    assert!(root.get_by_role(Role::Slider).is_none());
+
+    assert_eq!(root.get_all_by_tag(TagName::Label).len(), 2);
+    assert!(root.get_by_tag(TagName::Image).is_some());
+
+    assert_eq!(
+        root.get_by_text_containing("form").unwrap().text(),
+        Some("Submit the form")
+    );
+    assert_eq!(
+        root.get_by_text_matching(|text| text.ends_with(char::is_numeric))
+            .unwrap()
+            .text(),
+        Some("Cancel 42")
+    );
+    assert!(root.get_by_text_containing("Blabla").is_none());
+}
+
+#[tokio::test]
+async fn layout_snapshot() {
+    fn app() -> Element {
+        rsx!(
+            rect {
+                width: "50%",
+                height: "100",
+            }
+            rect {
+                width: "fill",
+                height: "fill",
+            }
+        )
+    }
+
+    let utils = launch_test(app);
+
+    let path = std::path::Path::new(env!("CARGO_TARGET_TMPDIR")).join("layout_snapshot.layout");
+    std::fs::remove_file(&path).ok();
+
+    // The first time the snapshot is stored
+    utils.assert_layout_snapshot(&path);
+    assert!(path.exists());
+
+    // Then it is compared with the stored snapshot
+    utils.assert_layout_snapshot(&path);
+
+    let snapshot = utils.layout_snapshot();
+    assert_eq!(LayoutSnapshot::parse(&snapshot.to_string()), Ok(snapshot));
+}
+
+#[tokio::test]
+async fn render_to_image() {
+    fn app() -> Element {
+        rsx!(
+            rect {
+                width: "50%",
+                height: "100%",
+                background: "red",
+            }
+        )
+    }
+
+    let mut utils = launch_test_with_config(
+        app,
+        TestingConfig {
+            size: (200.0, 100.0).into(),
+            ..TestingConfig::default()
+        },
+    );
+    utils.wait_for_update().await;
+
+    let image = utils.render_to_image();
+    assert_eq!(image.dimensions(), (200, 100));
+    assert_eq!(image.get_pixel(50, 50).0, [255, 0, 0, 255]);
+    assert_eq!(image.get_pixel(150, 50).0, [255, 255, 255, 255]);
+
+    let path = std::env::temp_dir().join("freya-testing-render.png");
+    utils.render_to_png(&path);
+    assert!(std::fs::read(&path).unwrap().starts_with(b"\x89PNG"));
+}
+
+#[tokio::test]
+async fn visual_regression() {
+    fn app() -> Element {
+        let mut clicked = use_signal(|| false);
+        let background = if clicked() { "blue" } else { "red" };
+
+        rsx!(rect {
+            width: "100%",
+            height: "100%",
+            background,
+            onclick: move |_| clicked.set(true),
+        })
+    }
+
+    let snapshots_path = concat!(env!("CARGO_TARGET_TMPDIR"), "/snapshots");
+    let mut utils = launch_test_with_config(
+        app,
+        TestingConfig {
+            size: (100.0, 100.0).into(),
+            snapshots_path,
+            ..TestingConfig::default()
+        },
+    );
+    utils.wait_for_update().await;
+
+    let path = std::path::Path::new(snapshots_path).join("visual_regression.png");
+    let diff_path = std::path::Path::new(snapshots_path).join("visual_regression.diff.png");
+    std::fs::remove_file(&path).ok();
+
+    // The first time the reference image is stored
+    utils.assert_snapshot("visual_regression");
+    assert!(path.exists());
+
+    // Then it is compared with the reference image
+    utils.assert_snapshot("visual_regression");
+    assert!(!diff_path.exists());
+
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::Click,
+        cursor: (5.0, 5.0).into(),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        utils.assert_snapshot("visual_regression")
+    }));
+    assert!(result.is_err());
+    assert!(diff_path.exists());
+}
+
+#[tokio::test]
+async fn interactions() {
+    fn app() -> Element {
+        let mut events = use_signal(Vec::<&str>::new);
+        let mut text = use_signal(String::new);
+
+        rsx!(
+            rect {
+                width: "100%",
+                height: "50%",
+                onmouseover: move |_| {
+                    if events.read().last() != Some(&"mouseover") {
+                        events.write().push("mouseover")
+                    }
+                },
+                onmousedown: move |_| events.write().push("mousedown"),
+                onpointerdown: move |_| events.write().push("pointerdown"),
+                onpointerup: move |_| events.write().push("pointerup"),
+                onclick: move |_| events.write().push("click"),
+                onwheel: move |_| events.write().push("wheel"),
+                label {
+                    "{events.read().join(\",\")}"
+                }
+            }
+            rect {
+                width: "100%",
+                height: "50%",
+                onkeydown: move |e| {
+                    if let Key::Character(character) = &e.key {
+                        text.write().push_str(character);
+                    } else if e.key == Key::Enter && e.modifiers.contains(Modifiers::SHIFT) {
+                        text.write().push('!');
+                    }
+                },
+                label {
+                    "{text}"
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(app);
+    utils.wait_for_update().await;
+
+    let events = utils.root().get(0);
+    let keys = utils.root().get(1);
+
+    utils.click(&events).await;
+    assert_eq!(
+        events.get(0).get(0).text(),
+        Some("mouseover,mousedown,pointerdown,pointerup,click")
+    );
+
+    utils.scroll_by(&events, (0., -50.)).await;
+    assert_eq!(
+        events.get(0).get(0).text(),
+        Some("mouseover,mousedown,pointerdown,pointerup,click,wheel")
+    );
+
+    utils.type_text("Hello").await;
+    utils.press_key(Key::Enter, Modifiers::SHIFT).await;
+    assert_eq!(keys.get(0).get(0).text(), Some("Hello!"));
+}
+
+#[tokio::test]
+async fn accessibility_tree() {
+    fn app() -> Element {
+        let focus = use_focus();
+
+        rsx!(
+            rect {
+                focus_id: focus.attribute(),
+                focusable: "true",
+                role: "button",
+                name: "Submit",
+                width: "100",
+                height: "50",
+            }
+            Button {
+                label {
+                    "Unnamed"
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(app);
+    utils.wait_for_update().await;
+
+    let tree = utils.accessibility_tree();
+    assert_eq!(tree.root.role, Role::Window);
+    assert_eq!(tree.focus, ACCESSIBILITY_ROOT_ID);
+    assert!(tree.focused().is_none());
+
+    let submit = tree.find_by_name("Submit").unwrap();
+    assert_eq!(submit.role, Role::Button);
+    assert!(submit.focusable);
+    assert_eq!(submit.bounds.unwrap().size, (100.0, 50.0).into());
+
+    // The Button component has no accessibility name
+    let unnamed = tree.unnamed_focusable_nodes();
+    assert_eq!(unnamed.len(), 1);
+    assert_eq!(unnamed[0].role, Role::Button);
+
+    assert!(tree.to_string().starts_with(
+        "Window name=\"Freya\"\n  Button name=\"Submit\" bounds=0,0,100,50 focusable\n"
+    ));
+}
+
+#[tokio::test]
+async fn replay() {
+    fn replay_app() -> Element {
+        let mut clicks = use_signal(|| 0);
+
+        rsx!(
+            rect {
+                width: "100",
+                height: "100",
+                onclick: move |_| clicks += 1,
+                label {
+                    "{clicks}"
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test_with_config(
+        replay_app,
+        TestingConfig {
+            virtual_clock: true,
+            ..TestingConfig::default()
+        },
+    );
+    utils.wait_for_update().await;
+
+    let recording = EventsRecording::parse(
+        "window_size 800 600
+scale_factor 2
+0 mouse mouseover 100 100 none
+20 mouse mousedown 100 100 left
+40 mouse click 100 100 left
+60 mouse mouseover 300 300 none
+80 mouse mousedown 300 300 left
+100 mouse click 300 300 left
+120 resize 1000 1000
+",
+    )
+    .unwrap();
+
+    utils.replay(&recording).await;
+
+    // Only the first click happened inside the rect, once scaled down
+    let label = utils.root().get(0).get(0);
+    assert_eq!(label.get(0).text(), Some("1"));
+    assert_eq!(utils.config().size, (500.0, 500.0).into());
+}
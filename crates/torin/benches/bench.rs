@@ -115,6 +115,8 @@ impl BenchmarkConfig {
 enum BenchmarkMode {
     NoCache,
     InvalidatedCache,
+    /// Invalidate a Node without changing its size, so its siblings can reuse their cache
+    InvalidatedCacheSameSize,
 }
 
 impl Display for BenchmarkMode {
@@ -122,6 +124,7 @@ impl Display for BenchmarkMode {
         match self {
             Self::NoCache => f.write_str("not cached"),
             Self::InvalidatedCache => f.write_str("cached"),
+            Self::InvalidatedCacheSameSize => f.write_str("cached same size"),
         }
     }
 }
@@ -218,6 +221,30 @@ fn criterion_benchmark(c: &mut Criterion) {
             node_generator: simple_node_generator,
             prefix: String::default(),
         },
+        BenchmarkConfig {
+            depth: 3,
+            wide: 100,
+            mode: BenchmarkMode::NoCache,
+            sample: 100,
+            node_generator: simple_node_generator,
+            prefix: String::default(),
+        },
+        BenchmarkConfig {
+            depth: 3,
+            wide: 100,
+            mode: BenchmarkMode::InvalidatedCache,
+            sample: 100,
+            node_generator: simple_node_generator,
+            prefix: String::default(),
+        },
+        BenchmarkConfig {
+            depth: 3,
+            wide: 100,
+            mode: BenchmarkMode::InvalidatedCacheSameSize,
+            sample: 100,
+            node_generator: simple_node_generator,
+            prefix: String::default(),
+        },
         BenchmarkConfig {
             depth: 8,
             wide: 4,
@@ -336,17 +363,22 @@ fn criterion_benchmark(c: &mut Criterion) {
 
                     let mut layout = Torin::<usize>::new();
 
-                    if mode == BenchmarkMode::InvalidatedCache {
+                    if mode != BenchmarkMode::NoCache {
                         layout.find_best_root(&mut mocked_dom);
                         layout.measure(0, root_area, &mut measurer, &mut mocked_dom);
-                        mocked_dom.set_node(
-                            invalidate_node,
+                        let node = if mode == BenchmarkMode::InvalidatedCache {
                             Node::from_size_and_direction(
                                 Size::Inner,
                                 Size::Pixels(Length::new(10.0)),
                                 DirectionMode::Vertical,
-                            ),
-                        );
+                            )
+                        } else {
+                            Node {
+                                direction: DirectionMode::Horizontal,
+                                ..node_generator(depth)
+                            }
+                        };
+                        mocked_dom.set_node(invalidate_node, node);
                         layout.invalidate(invalidate_node);
                     }
 
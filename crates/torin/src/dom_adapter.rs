@@ -26,6 +26,22 @@ pub struct LayoutNode {
 
     /// Associated data
     pub data: Option<Arc<SendAnyMap>>,
+
+    /// Constraints this Node was measured with, only known for final measurements
+    pub constraints: Option<LayoutConstraints>,
+}
+
+/// Inputs from outside a Node that affect its measurement
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct LayoutConstraints {
+    /// Area occupied by its parent
+    pub parent_area: Area,
+
+    /// Area that was available in its parent
+    pub available_parent_area: Area,
+
+    /// Area of the root Node
+    pub root_area: Area,
 }
 
 impl PartialEq for LayoutNode {
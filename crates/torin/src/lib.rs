@@ -5,6 +5,7 @@ mod measure;
 mod measure_mode;
 pub mod node;
 pub mod scaled;
+pub mod snapshot;
 pub mod torin;
 pub mod values;
 
@@ -17,6 +18,7 @@ pub mod prelude {
     pub use crate::geometry::*;
     pub use crate::node::*;
     pub use crate::scaled::*;
+    pub use crate::snapshot::*;
     pub use crate::torin::*;
     pub use crate::values::prelude::*;
 }
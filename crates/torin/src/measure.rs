@@ -1,13 +1,18 @@
+use std::borrow::Cow;
+
 pub use euclid::Rect;
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHashSet};
 
 use crate::{
     custom_measurer::LayoutMeasurer,
-    dom_adapter::{DOMAdapter, LayoutNode, NodeKey},
-    geometry::{Area, Size2D},
+    dom_adapter::{DOMAdapter, LayoutConstraints, LayoutNode, NodeKey},
+    geometry::{Area, Length, Point2D, Size2D},
+    grid::GridTrack,
     measure_mode::MeasureMode,
     node::Node,
-    prelude::{AlignmentDirection, AreaModel, LayoutMetadata, Torin},
+    prelude::{
+        Alignment, AlignmentDirection, AreaModel, DirectionMode, LayoutMetadata, Size, Torin,
+    },
 };
 
 /// Some layout strategies require two-phase measurements
@@ -41,15 +46,42 @@ pub fn measure_node<Key: NodeKey>(
 
     phase: Phase,
 ) -> (bool, LayoutNode) {
-    let must_revalidate = invalidated_tree
-        || layout.dirty.contains(&node_id)
-        || !layout.results.contains_key(&node_id);
+    // Give a size to the unspecified axis of Nodes with an aspect ratio
+    let (width, height) = resolve_aspect_ratio(
+        node,
+        parent_area,
+        available_parent_area,
+        layout_metadata,
+        phase,
+    );
+
+    let constraints = LayoutConstraints {
+        parent_area: *parent_area,
+        available_parent_area: *available_parent_area,
+        root_area: layout_metadata.root_area,
+    };
+
+    let must_revalidate = layout.dirty.contains(&node_id)
+        || match layout.get(node_id) {
+            None => true,
+            // Final measurements can be reused as long as they were measured with the same constraints
+            Some(layout_node) if phase == Phase::Final => {
+                layout_node.constraints.as_ref() != Some(&constraints)
+            }
+            Some(_) => invalidated_tree,
+        };
+
+    // Skip the whole subtree if nothing has changed in it
+    if !must_revalidate && phase == Phase::Final && !layout.dirty_ancestors.contains(&node_id) {
+        return (false, layout.get(node_id).unwrap().clone());
+    }
+
     if must_revalidate {
         // Create the initial Node area size
         let mut area_size = Size2D::new(node.padding.horizontal(), node.padding.vertical());
 
         // Compute the width and height given the size, the minimum size, the maximum size and margins
-        area_size.width = node.width.min_max(
+        area_size.width = width.min_max(
             area_size.width,
             parent_area.size.width,
             available_parent_area.size.width,
@@ -60,7 +92,7 @@ pub fn measure_node<Key: NodeKey>(
             layout_metadata.root_area.width(),
             phase,
         );
-        area_size.height = node.height.min_max(
+        area_size.height = height.min_max(
             area_size.height,
             parent_area.size.height,
             available_parent_area.size.height,
@@ -76,20 +108,18 @@ pub fn measure_node<Key: NodeKey>(
         // This is useful when you use third-party libraries (e.g. rust-skia, cosmic-text) to measure text layouts
         // When a Node is measured by a custom measurer function the inner children will be skipped
         let (measure_inner_children, node_data) = if let Some(measurer) = measurer {
-            let most_fitting_width = *node
-                .width
-                .most_fitting_size(&area_size.width, &available_parent_area.size.width);
-            let most_fitting_height = *node
-                .height
-                .most_fitting_size(&area_size.height, &available_parent_area.size.height);
+            let most_fitting_width =
+                *width.most_fitting_size(&area_size.width, &available_parent_area.size.width);
+            let most_fitting_height =
+                *height.most_fitting_size(&area_size.height, &available_parent_area.size.height);
 
             let most_fitting_area_size = Size2D::new(most_fitting_width, most_fitting_height);
             let res = measurer.measure(node_id, node, &most_fitting_area_size);
 
             // Compute the width and height again using the new custom area sizes
             if let Some((custom_size, node_data)) = res {
-                if node.width.inner_sized() {
-                    area_size.width = node.width.min_max(
+                if width.inner_sized() {
+                    area_size.width = width.min_max(
                         custom_size.width,
                         parent_area.size.width,
                         available_parent_area.size.width,
@@ -101,8 +131,8 @@ pub fn measure_node<Key: NodeKey>(
                         phase,
                     );
                 }
-                if node.height.inner_sized() {
-                    area_size.height = node.height.min_max(
+                if height.inner_sized() {
+                    area_size.height = height.min_max(
                         custom_size.height,
                         parent_area.size.height,
                         available_parent_area.size.height,
@@ -127,7 +157,7 @@ pub fn measure_node<Key: NodeKey>(
         // There is no need to measure inner children in the initial phase if this Node size
         // isn't decided by his children
         let phase_measure_inner_children = if phase == Phase::Initial {
-            node.width.inner_sized() || node.height.inner_sized()
+            width.inner_sized() || height.inner_sized()
         } else {
             true
         };
@@ -137,8 +167,8 @@ pub fn measure_node<Key: NodeKey>(
             let mut inner_size = area_size;
 
             // When having an unsized bound we set it to whatever is still available in the parent's area
-            if node.width.inner_sized() {
-                inner_size.width = node.width.min_max(
+            if width.inner_sized() {
+                inner_size.width = width.min_max(
                     available_parent_area.width(),
                     parent_area.size.width,
                     available_parent_area.width(),
@@ -150,8 +180,8 @@ pub fn measure_node<Key: NodeKey>(
                     phase,
                 );
             }
-            if node.height.inner_sized() {
-                inner_size.height = node.height.min_max(
+            if height.inner_sized() {
+                inner_size.height = height.min_max(
                     available_parent_area.height(),
                     parent_area.size.height,
                     available_parent_area.height(),
@@ -187,6 +217,8 @@ pub fn measure_node<Key: NodeKey>(
             let mut measurement_mode = MeasureMode::ParentIsNotCached {
                 area: &mut area,
                 inner_area: &mut inner_area,
+                width_inner_sized: width.inner_sized(),
+                height_inner_sized: height.inner_sized(),
             };
 
             // Measure the layout of this Node's children
@@ -213,6 +245,7 @@ pub fn measure_node<Key: NodeKey>(
                 inner_area,
                 inner_sizes,
                 data: node_data,
+                constraints: (phase == Phase::Final).then_some(constraints),
             },
         )
     } else {
@@ -253,6 +286,116 @@ pub fn measure_node<Key: NodeKey>(
     }
 }
 
+/// Get the width and height of a Node, sizing its unspecified axis given its aspect ratio and the size of its other axis.
+/// The aspect ratio is not used when both axis are unspecified, as there is no size to derive the other one from.
+fn resolve_aspect_ratio<'a>(
+    node: &'a Node,
+    parent_area: &Area,
+    available_parent_area: &Area,
+    layout_metadata: &LayoutMetadata,
+    phase: Phase,
+) -> (Cow<'a, Size>, Cow<'a, Size>) {
+    let Some(aspect_ratio) = node.aspect_ratio else {
+        return (Cow::Borrowed(&node.width), Cow::Borrowed(&node.height));
+    };
+
+    match (node.width == Size::Inner, node.height == Size::Inner) {
+        (true, false) => {
+            // The height might not be decided yet in this phase
+            if node
+                .height
+                .eval(
+                    parent_area.height(),
+                    available_parent_area.height(),
+                    node.margin.vertical(),
+                    layout_metadata.root_area.height(),
+                    phase,
+                )
+                .is_none()
+            {
+                return (Cow::Borrowed(&node.width), Cow::Borrowed(&node.height));
+            }
+
+            let height = node.height.min_max(
+                node.padding.vertical(),
+                parent_area.height(),
+                available_parent_area.height(),
+                node.margin.top(),
+                node.margin.vertical(),
+                &node.minimum_height,
+                &node.maximum_height,
+                layout_metadata.root_area.height(),
+                phase,
+            ) - node.margin.vertical();
+
+            // The width still respects its own minimum and maximum
+            let width = Size::Pixels(Length::new(height * aspect_ratio)).min_max(
+                0.0,
+                parent_area.width(),
+                available_parent_area.width(),
+                node.margin.left(),
+                node.margin.horizontal(),
+                &node.minimum_width,
+                &node.maximum_width,
+                layout_metadata.root_area.width(),
+                phase,
+            ) - node.margin.horizontal();
+
+            (
+                Cow::Owned(Size::Pixels(Length::new(width))),
+                Cow::Borrowed(&node.height),
+            )
+        }
+        (false, true) => {
+            // The width might not be decided yet in this phase
+            if node
+                .width
+                .eval(
+                    parent_area.width(),
+                    available_parent_area.width(),
+                    node.margin.horizontal(),
+                    layout_metadata.root_area.width(),
+                    phase,
+                )
+                .is_none()
+            {
+                return (Cow::Borrowed(&node.width), Cow::Borrowed(&node.height));
+            }
+
+            let width = node.width.min_max(
+                node.padding.horizontal(),
+                parent_area.width(),
+                available_parent_area.width(),
+                node.margin.left(),
+                node.margin.horizontal(),
+                &node.minimum_width,
+                &node.maximum_width,
+                layout_metadata.root_area.width(),
+                phase,
+            ) - node.margin.horizontal();
+
+            // The height still respects its own minimum and maximum
+            let height = Size::Pixels(Length::new(width / aspect_ratio)).min_max(
+                0.0,
+                parent_area.height(),
+                available_parent_area.height(),
+                node.margin.top(),
+                node.margin.vertical(),
+                &node.minimum_height,
+                &node.maximum_height,
+                layout_metadata.root_area.height(),
+                phase,
+            ) - node.margin.vertical();
+
+            (
+                Cow::Borrowed(&node.width),
+                Cow::Owned(Size::Pixels(Length::new(height))),
+            )
+        }
+        _ => (Cow::Borrowed(&node.width), Cow::Borrowed(&node.height)),
+    }
+}
+
 /// Measure the children layouts of a Node
 #[allow(clippy::too_many_arguments)]
 #[inline(always)]
@@ -275,27 +418,86 @@ pub fn measure_inner_nodes<Key: NodeKey>(
 
     invalidated_tree: bool,
 ) {
-    let children = dom_adapter.children_of(parent_node_id);
+    let children = dom_adapter
+        .children_of(parent_node_id)
+        .into_iter()
+        .filter_map(|child_id| Some((child_id, dom_adapter.get_node(&child_id)?)))
+        .collect::<Vec<(Key, Node)>>();
+
+    if parent_node.is_grid() {
+        measure_grid_inner_nodes(
+            parent_node,
+            children,
+            layout,
+            available_area,
+            inner_sizes,
+            measurer,
+            must_cache_inner_nodes,
+            mode,
+            dom_adapter,
+            layout_metadata,
+            invalidated_tree,
+        );
+        return;
+    }
+
+    if parent_node.wrap_content.is_wrap() {
+        measure_wrapped_inner_nodes(
+            parent_node,
+            children,
+            layout,
+            available_area,
+            inner_sizes,
+            measurer,
+            must_cache_inner_nodes,
+            mode,
+            dom_adapter,
+            layout_metadata,
+            invalidated_tree,
+        );
+        return;
+    }
 
     let mut initial_phase_sizes = FxHashMap::default();
 
+    // Weights of the children that use a flex size in the main axis
+    let flex_grows = children
+        .iter()
+        .filter_map(|(child_id, child_data)| {
+            Some((*child_id, child_data.flex_grow(&parent_node.direction)?))
+        })
+        .collect::<FxHashMap<Key, f32>>();
+    let mut flex_sizes = FxHashMap::default();
+
+    // Extra space between the children when the Main axis is spaced
+    let mut main_gap = 0.0;
+
+    // Whether any child is aligned in the Cross axis, by the parent or by itself
+    let is_cross_aligned = parent_node.cross_alignment.is_not_start()
+        || children.iter().any(|(_, child_data)| {
+            child_data
+                .align_self
+                .as_ref()
+                .is_some_and(Alignment::is_not_start)
+        });
+
     // Initial phase: Measure the size and position of the children if the parent has a
-    // non-start cross alignment, non-start main aligment of a fit-content.
-    if parent_node.cross_alignment.is_not_start()
+    // non-start cross alignment, non-start main aligment, a fit-content or flex children.
+    if is_cross_aligned
         || parent_node.main_alignment.is_not_start()
         || parent_node.content.is_fit()
+        || !flex_grows.is_empty()
     {
         let mut initial_phase_mode = mode.to_owned();
         let mut initial_phase_mode = initial_phase_mode.to_mut();
         let mut initial_phase_inner_sizes = *inner_sizes;
         let mut initial_phase_available_area = *available_area;
 
-        // 1. Measure the children
-        for child_id in &children {
-            let Some(child_data) = dom_adapter.get_node(child_id) else {
-                continue;
-            };
+        // Main axis size occupied by the non-flex children
+        let mut fixed_main_size = 0.0;
 
+        // 1. Measure the children
+        for (child_id, child_data) in &children {
             if child_data.position.is_absolute() {
                 continue;
             }
@@ -304,7 +506,7 @@ pub fn measure_inner_nodes<Key: NodeKey>(
 
             let (_, child_areas) = measure_node(
                 *child_id,
-                &child_data,
+                child_data,
                 layout,
                 &inner_area,
                 &initial_phase_available_area,
@@ -321,58 +523,116 @@ pub fn measure_inner_nodes<Key: NodeKey>(
                 &mut initial_phase_available_area,
                 &child_areas.area,
                 &mut initial_phase_inner_sizes,
-                &child_data,
+                child_data,
             );
 
-            if parent_node.cross_alignment.is_not_start() {
+            if is_cross_aligned {
                 initial_phase_sizes.insert(*child_id, child_areas.area.size);
             }
+
+            if !flex_grows.contains_key(child_id) {
+                fixed_main_size += main_axis_size(&parent_node.direction, &child_areas.area.size);
+            }
+        }
+
+        if !flex_grows.is_empty() {
+            // 2. Share the remaining space of the Main axis between the flex children
+            let inner_area = *initial_phase_mode.inner_area();
+            let flex_available_size =
+                main_axis_size(&parent_node.direction, &available_area.size) - fixed_main_size;
+            flex_sizes = resolve_flex_sizes(
+                parent_node,
+                &children,
+                &flex_grows,
+                main_axis_size(&parent_node.direction, &inner_area.size),
+                flex_available_size,
+                main_axis_size(&parent_node.direction, &layout_metadata.root_area.size),
+            );
+
+            // The flex children now occupy their final sizes
+            let main_size = fixed_main_size + flex_sizes.values().sum::<f32>();
+            match parent_node.direction {
+                DirectionMode::Vertical => initial_phase_inner_sizes.height = main_size,
+                DirectionMode::Horizontal => initial_phase_inner_sizes.width = main_size,
+            }
         }
 
         if parent_node.main_alignment.is_not_start() {
-            // 2. Adjust the available and inner areas of the Main axis
+            // 3. Adjust the available and inner areas of the Main axis
             initial_phase_mode.fit_bounds_when_unspecified(
                 parent_node,
                 AlignmentDirection::Main,
                 available_area,
+                is_cross_aligned,
             );
 
-            // 3. Align the Main axis
-            available_area.align_content(
-                initial_phase_mode.inner_area(),
-                &initial_phase_inner_sizes,
-                &parent_node.main_alignment,
-                &parent_node.direction,
-                AlignmentDirection::Main,
-            );
+            // 4. Align the Main axis
+            if parent_node.main_alignment.is_spaced() {
+                let direction = &parent_node.direction;
+                let inner_area = initial_phase_mode.inner_area();
+                let free_space = main_axis_size(direction, &inner_area.size)
+                    - main_axis_size(direction, &initial_phase_inner_sizes);
+                let stacked_children = children
+                    .iter()
+                    .filter(|(_, child_data)| !child_data.position.is_absolute())
+                    .count();
+                let (offset, gap) =
+                    alignment_offsets(&parent_node.main_alignment, free_space, stacked_children);
+                match direction {
+                    DirectionMode::Vertical => {
+                        available_area.origin.y = inner_area.min_y() + offset;
+                        available_area.size.height = inner_area.height() - offset;
+                    }
+                    DirectionMode::Horizontal => {
+                        available_area.origin.x = inner_area.min_x() + offset;
+                        available_area.size.width = inner_area.width() - offset;
+                    }
+                }
+                main_gap = gap;
+            } else {
+                available_area.align_content(
+                    initial_phase_mode.inner_area(),
+                    &initial_phase_inner_sizes,
+                    &parent_node.main_alignment,
+                    &parent_node.direction,
+                    AlignmentDirection::Main,
+                );
+            }
         }
 
-        if parent_node.cross_alignment.is_not_start() || parent_node.content.is_fit() {
-            // 4. Adjust the available and inner areas of the Cross axis
+        if is_cross_aligned || parent_node.content.is_fit() {
+            // 5. Adjust the available and inner areas of the Cross axis
             initial_phase_mode.fit_bounds_when_unspecified(
                 parent_node,
                 AlignmentDirection::Cross,
                 available_area,
+                is_cross_aligned,
             );
         }
     }
 
     // Final phase: measure the children with all the axis and sizes adjusted
-    for child_id in children {
-        let Some(child_data) = dom_adapter.get_node(&child_id) else {
-            continue;
-        };
-
+    for (child_id, child_data) in children {
         let mut adapted_available_area = *available_area;
-        if parent_node.cross_alignment.is_not_start() {
+
+        // 1. Constrain the Main axis to the flex size if necessary
+        if let Some(flex_size) = flex_sizes.get(&child_id) {
+            match parent_node.direction {
+                DirectionMode::Vertical => adapted_available_area.size.height = *flex_size,
+                DirectionMode::Horizontal => adapted_available_area.size.width = *flex_size,
+            }
+        }
+
+        let cross_alignment = parent_node.inner_cross_alignment(&child_data);
+        if cross_alignment.is_not_start() {
             let initial_phase_size = initial_phase_sizes.get(&child_id);
 
             if let Some(initial_phase_size) = initial_phase_size {
-                // 1. Align the Cross axis if necessary
+                // 2. Align the Cross axis if necessary
                 adapted_available_area.align_content(
                     available_area,
                     initial_phase_size,
-                    &parent_node.cross_alignment,
+                    cross_alignment,
                     &parent_node.direction,
                     AlignmentDirection::Cross,
                 );
@@ -405,6 +665,20 @@ pub fn measure_inner_nodes<Key: NodeKey>(
             &child_data,
         );
 
+        // Leave the spaced gap after the child
+        if main_gap > 0.0 && !child_data.position.is_absolute() {
+            match parent_node.direction {
+                DirectionMode::Vertical => {
+                    available_area.origin.y += main_gap;
+                    available_area.size.height -= main_gap;
+                }
+                DirectionMode::Horizontal => {
+                    available_area.origin.x += main_gap;
+                    available_area.size.width -= main_gap;
+                }
+            }
+        }
+
         // Cache the child layout if it was mutated and inner nodes must be cache
         if child_revalidated && must_cache_inner_nodes {
             if let Some(measurer) = measurer {
@@ -416,3 +690,727 @@ pub fn measure_inner_nodes<Key: NodeKey>(
         }
     }
 }
+
+/// A line of wrapped children
+#[derive(Default)]
+struct WrapLine {
+    /// Index and initial size of the children in this line
+    children: Vec<(usize, Size2D)>,
+    main_size: f32,
+    cross_size: f32,
+}
+
+/// Measure the children layouts of a Node that wraps them into multiple lines
+#[allow(clippy::too_many_arguments)]
+#[inline(always)]
+fn measure_wrapped_inner_nodes<Key: NodeKey>(
+    parent_node: &Node,
+    children: Vec<(Key, Node)>,
+    layout: &mut Torin<Key>,
+    // Area available inside the Node
+    available_area: &mut Area,
+    // Accumulated sizes in both axis in the Node
+    inner_sizes: &mut Size2D,
+    measurer: &mut Option<impl LayoutMeasurer<Key>>,
+    // Whether to cache the measurements of this Node's children
+    must_cache_inner_nodes: bool,
+    mode: &mut MeasureMode,
+    // Adapter for the provided DOM
+    dom_adapter: &mut impl DOMAdapter<Key>,
+
+    layout_metadata: &LayoutMetadata,
+
+    invalidated_tree: bool,
+) {
+    let direction = &parent_node.direction;
+    let (main_spacing, cross_spacing) = match direction {
+        DirectionMode::Vertical => (parent_node.spacing.top(), parent_node.spacing.left()),
+        DirectionMode::Horizontal => (parent_node.spacing.left(), parent_node.spacing.top()),
+    };
+    let available_main_size = main_axis_size(direction, &available_area.size);
+
+    // 1. Measure the children and distribute them in lines
+    let mut lines = vec![WrapLine::default()];
+    for (i, (child_id, child_data)) in children.iter().enumerate() {
+        if child_data.position.is_absolute() {
+            continue;
+        }
+
+        let inner_area = *mode.inner_area();
+
+        let (_, child_areas) = measure_node(
+            *child_id,
+            child_data,
+            layout,
+            &inner_area,
+            available_area,
+            measurer,
+            false,
+            dom_adapter,
+            layout_metadata,
+            invalidated_tree,
+            Phase::Initial,
+        );
+
+        let child_main_size = main_axis_size(direction, &child_areas.area.size);
+        let child_cross_size = cross_axis_size(direction, &child_areas.area.size);
+
+        let mut line = lines.last_mut().unwrap();
+        if !line.children.is_empty()
+            && line.main_size + main_spacing + child_main_size > available_main_size
+        {
+            lines.push(WrapLine::default());
+            line = lines.last_mut().unwrap();
+        }
+
+        if !line.children.is_empty() {
+            line.main_size += main_spacing;
+        }
+        line.main_size += child_main_size;
+        line.cross_size = line.cross_size.max(child_cross_size);
+        line.children.push((i, child_areas.area.size));
+    }
+
+    // 2. Share the remaining space of the Main axis of every line between its flex children
+    let mut flex_sizes = FxHashMap::default();
+    for line in &mut lines {
+        let mut flex_grows = FxHashMap::default();
+        let mut flex_main_size = 0.0;
+        for (i, initial_phase_size) in &line.children {
+            let (child_id, child_data) = &children[*i];
+            if let Some(flex_grow) = child_data.flex_grow(direction) {
+                flex_grows.insert(*child_id, flex_grow);
+                flex_main_size += main_axis_size(direction, initial_phase_size);
+            }
+        }
+
+        if flex_grows.is_empty() {
+            continue;
+        }
+
+        let fixed_main_size = line.main_size - flex_main_size;
+        let line_flex_sizes = resolve_flex_sizes(
+            parent_node,
+            &children,
+            &flex_grows,
+            main_axis_size(direction, &mode.inner_area().size),
+            available_main_size - fixed_main_size,
+            main_axis_size(direction, &layout_metadata.root_area.size),
+        );
+
+        // The flex children now occupy their final sizes
+        line.main_size = fixed_main_size + line_flex_sizes.values().sum::<f32>();
+        flex_sizes.extend(line_flex_sizes);
+    }
+
+    // 3. Fit the Node to its lines
+    let content_main_size = lines.iter().map(|line| line.main_size).fold(0.0, f32::max);
+    let content_cross_size = lines.iter().map(|line| line.cross_size).sum::<f32>()
+        + cross_spacing * (lines.len() - 1) as f32;
+    fit_inner_sizes(
+        parent_node,
+        mode,
+        available_area,
+        inner_sizes,
+        size_from_axis(direction, content_main_size, content_cross_size),
+    );
+
+    // 4. Measure the children in their lines, aligning the lines in the Main axis
+    // and the children in the Cross axis of their line
+    let available_main_size = main_axis_size(direction, &available_area.size);
+    let available_cross_size = cross_axis_size(direction, &available_area.size);
+    let mut cross_offset = alignment_offset(
+        &parent_node.cross_alignment,
+        available_cross_size - content_cross_size,
+    );
+    for line in lines {
+        let (mut main_offset, main_gap) = alignment_offsets(
+            &parent_node.main_alignment,
+            available_main_size - line.main_size,
+            line.children.len(),
+        );
+
+        for (i, initial_phase_size) in line.children {
+            let (child_id, child_data) = &children[i];
+
+            let child_cross_offset = alignment_offset(
+                parent_node.inner_cross_alignment(child_data),
+                line.cross_size - cross_axis_size(direction, &initial_phase_size),
+            );
+            let offset = size_from_axis(direction, main_offset, cross_offset + child_cross_offset);
+            let child_main_size = flex_sizes
+                .get(child_id)
+                .copied()
+                .unwrap_or(available_main_size - main_offset);
+            let adapted_available_area = Area::new(
+                available_area.origin + offset,
+                size_from_axis(
+                    direction,
+                    child_main_size,
+                    line.cross_size - child_cross_offset,
+                ),
+            );
+
+            let child_areas = measure_placed_node(
+                *child_id,
+                child_data,
+                layout,
+                mode.inner_area(),
+                &adapted_available_area,
+                measurer,
+                must_cache_inner_nodes,
+                dom_adapter,
+                layout_metadata,
+                invalidated_tree,
+            );
+
+            main_offset +=
+                main_axis_size(direction, &child_areas.area.size) + main_spacing + main_gap;
+        }
+
+        cross_offset += line.cross_size + cross_spacing;
+    }
+
+    // 5. Measure the absolute children
+    for (child_id, child_data) in &children {
+        if child_data.position.is_absolute() {
+            measure_placed_node(
+                *child_id,
+                child_data,
+                layout,
+                mode.inner_area(),
+                available_area,
+                measurer,
+                must_cache_inner_nodes,
+                dom_adapter,
+                layout_metadata,
+                invalidated_tree,
+            );
+        }
+    }
+}
+
+/// Measure a Node that was already placed by its parent, and cache it if necessary
+#[allow(clippy::too_many_arguments)]
+#[inline(always)]
+fn measure_placed_node<Key: NodeKey>(
+    child_id: Key,
+    child_data: &Node,
+    layout: &mut Torin<Key>,
+    // Area occupied by it's parent
+    parent_area: &Area,
+    // Area that is available to use by this Node
+    available_area: &Area,
+    measurer: &mut Option<impl LayoutMeasurer<Key>>,
+    must_cache_inner_nodes: bool,
+    dom_adapter: &mut impl DOMAdapter<Key>,
+    layout_metadata: &LayoutMetadata,
+    invalidated_tree: bool,
+) -> LayoutNode {
+    let (child_revalidated, child_areas) = measure_node(
+        child_id,
+        child_data,
+        layout,
+        parent_area,
+        available_area,
+        measurer,
+        must_cache_inner_nodes,
+        dom_adapter,
+        layout_metadata,
+        invalidated_tree,
+        Phase::Final,
+    );
+
+    // Cache the child layout if it was mutated and inner nodes must be cache
+    if child_revalidated && must_cache_inner_nodes {
+        if let Some(measurer) = measurer {
+            if child_data.has_layout_references {
+                measurer.notify_layout_references(child_id, &child_areas);
+            }
+        }
+        layout.cache_node(child_id, child_areas.clone());
+    }
+
+    child_areas
+}
+
+/// Cell of a grid occupied by a Node
+struct GridCell {
+    column: usize,
+    column_span: usize,
+    row: usize,
+    row_span: usize,
+}
+
+/// Measure the children layouts of a Node that places them in a grid
+#[allow(clippy::too_many_arguments)]
+#[inline(always)]
+fn measure_grid_inner_nodes<Key: NodeKey>(
+    parent_node: &Node,
+    children: Vec<(Key, Node)>,
+    layout: &mut Torin<Key>,
+    // Area available inside the Node
+    available_area: &mut Area,
+    // Accumulated sizes in both axis in the Node
+    inner_sizes: &mut Size2D,
+    measurer: &mut Option<impl LayoutMeasurer<Key>>,
+    // Whether to cache the measurements of this Node's children
+    must_cache_inner_nodes: bool,
+    mode: &mut MeasureMode,
+    // Adapter for the provided DOM
+    dom_adapter: &mut impl DOMAdapter<Key>,
+
+    layout_metadata: &LayoutMetadata,
+
+    invalidated_tree: bool,
+) {
+    let row_spacing = parent_node.spacing.top();
+    let column_spacing = parent_node.spacing.left();
+
+    // 1. Place the children in the cells of the grid
+    let mut columns = parent_node.grid_columns.clone();
+    if columns.is_empty() {
+        columns.push(GridTrack::Auto);
+    }
+    let mut rows = parent_node.grid_rows.clone();
+    let cells = place_grid_children(&children, &mut columns, &mut rows);
+
+    // 2. Measure the children of the auto-sized tracks
+    let mut columns_content_sizes = vec![0.0; columns.len()];
+    let mut rows_content_sizes = vec![0.0; rows.len()];
+    for (i, cell) in &cells {
+        let (child_id, child_data) = &children[*i];
+
+        // Only the children that occupy a single track and don't depend on its size are used
+        let is_auto_column = cell.column_span == 1
+            && columns[cell.column] == GridTrack::Auto
+            && !child_data.width.depends_on_available();
+        let is_auto_row = cell.row_span == 1
+            && rows[cell.row] == GridTrack::Auto
+            && !child_data.height.depends_on_available();
+        if !is_auto_column && !is_auto_row {
+            continue;
+        }
+
+        let inner_area = *mode.inner_area();
+
+        let (_, child_areas) = measure_node(
+            *child_id,
+            child_data,
+            layout,
+            &inner_area,
+            available_area,
+            measurer,
+            false,
+            dom_adapter,
+            layout_metadata,
+            invalidated_tree,
+            Phase::Initial,
+        );
+
+        if is_auto_column {
+            let column_size = &mut columns_content_sizes[cell.column];
+            *column_size = child_areas.area.width().max(*column_size);
+        }
+        if is_auto_row {
+            let row_size = &mut rows_content_sizes[cell.row];
+            *row_size = child_areas.area.height().max(*row_size);
+        }
+    }
+
+    // 3. Resolve the sizes of the tracks
+    let columns_sizes = resolve_grid_tracks(
+        &columns,
+        &columns_content_sizes,
+        available_area.width(),
+        column_spacing,
+    );
+    let rows_sizes = resolve_grid_tracks(
+        &rows,
+        &rows_content_sizes,
+        available_area.height(),
+        row_spacing,
+    );
+
+    // 4. Fit the Node to its tracks
+    fit_inner_sizes(
+        parent_node,
+        mode,
+        available_area,
+        inner_sizes,
+        Size2D::new(
+            tracks_size(&columns_sizes, column_spacing),
+            tracks_size(&rows_sizes, row_spacing),
+        ),
+    );
+
+    // 5. Measure the children in their cells
+    let columns_offsets = tracks_offsets(&columns_sizes, column_spacing);
+    let rows_offsets = tracks_offsets(&rows_sizes, row_spacing);
+    for (i, cell) in cells {
+        let (child_id, child_data) = &children[i];
+
+        let columns_range = cell.column..cell.column + cell.column_span;
+        let rows_range = cell.row..cell.row + cell.row_span;
+        let cell_area = Area::new(
+            Point2D::new(
+                available_area.min_x() + columns_offsets[cell.column],
+                available_area.min_y() + rows_offsets[cell.row],
+            ),
+            Size2D::new(
+                tracks_size(&columns_sizes[columns_range], column_spacing),
+                tracks_size(&rows_sizes[rows_range], row_spacing),
+            ),
+        );
+
+        measure_placed_node(
+            *child_id,
+            child_data,
+            layout,
+            &cell_area,
+            &cell_area,
+            measurer,
+            must_cache_inner_nodes,
+            dom_adapter,
+            layout_metadata,
+            invalidated_tree,
+        );
+    }
+
+    // 6. Measure the absolute children
+    for (child_id, child_data) in &children {
+        if child_data.position.is_absolute() {
+            measure_placed_node(
+                *child_id,
+                child_data,
+                layout,
+                mode.inner_area(),
+                available_area,
+                measurer,
+                must_cache_inner_nodes,
+                dom_adapter,
+                layout_metadata,
+                invalidated_tree,
+            );
+        }
+    }
+}
+
+/// Place the children of a grid in their cells. Those without an explicit placement are placed in the first free cells.
+/// The columns and rows are extended with implicit auto-sized tracks when necessary.
+fn place_grid_children<Key: NodeKey>(
+    children: &[(Key, Node)],
+    columns: &mut Vec<GridTrack>,
+    rows: &mut Vec<GridTrack>,
+) -> Vec<(usize, GridCell)> {
+    let is_free = |occupied: &FxHashSet<(usize, usize)>, cell: &GridCell| {
+        (cell.row..cell.row + cell.row_span).all(|row| {
+            (cell.column..cell.column + cell.column_span)
+                .all(|column| !occupied.contains(&(column, row)))
+        })
+    };
+
+    let mut occupied = FxHashSet::default();
+    let mut cursor = (0, 0);
+    let mut cells = Vec::new();
+
+    for (i, (_, child_data)) in children.iter().enumerate() {
+        if child_data.position.is_absolute() {
+            continue;
+        }
+
+        let mut cell = GridCell {
+            column: child_data.grid_column.start.unwrap_or_default(),
+            column_span: child_data.grid_column.span.max(1),
+            row: child_data.grid_row.start.unwrap_or_default(),
+            row_span: child_data.grid_row.span.max(1),
+        };
+
+        match (child_data.grid_column.start, child_data.grid_row.start) {
+            (Some(_), Some(_)) => {}
+            (Some(_), None) => {
+                while !is_free(&occupied, &cell) {
+                    cell.row += 1;
+                }
+            }
+            (None, Some(_)) => {
+                while !is_free(&occupied, &cell) {
+                    cell.column += 1;
+                }
+            }
+            (None, None) => {
+                cell.column_span = cell.column_span.min(columns.len());
+                (cell.column, cell.row) = cursor;
+                loop {
+                    if cell.column + cell.column_span > columns.len() {
+                        cell.column = 0;
+                        cell.row += 1;
+                    } else if is_free(&occupied, &cell) {
+                        break;
+                    } else {
+                        cell.column += 1;
+                    }
+                }
+                cursor = (cell.column, cell.row);
+            }
+        }
+
+        for row in cell.row..cell.row + cell.row_span {
+            for column in cell.column..cell.column + cell.column_span {
+                occupied.insert((column, row));
+            }
+        }
+
+        if columns.len() < cell.column + cell.column_span {
+            columns.resize(cell.column + cell.column_span, GridTrack::Auto);
+        }
+        if rows.len() < cell.row + cell.row_span {
+            rows.resize(cell.row + cell.row_span, GridTrack::Auto);
+        }
+
+        cells.push((i, cell));
+    }
+
+    cells
+}
+
+/// Resolve the sizes of the tracks of a grid in one axis
+fn resolve_grid_tracks(
+    tracks: &[GridTrack],
+    content_sizes: &[f32],
+    available_size: f32,
+    spacing: f32,
+) -> Vec<f32> {
+    let mut sizes = tracks
+        .iter()
+        .zip(content_sizes)
+        .map(|(track, content_size)| match track {
+            GridTrack::Auto => *content_size,
+            GridTrack::Pixels(px) => px.get(),
+            GridTrack::Percentage(per) => available_size / 100.0 * per.get(),
+            GridTrack::Fraction(_) => 0.0,
+        })
+        .collect::<Vec<f32>>();
+
+    let total_fraction = tracks
+        .iter()
+        .filter_map(|track| match track {
+            GridTrack::Fraction(fr) => Some(fr.get()),
+            _ => None,
+        })
+        .sum::<f32>();
+
+    // Share the remaining space between the fractional tracks
+    if total_fraction > 0.0 {
+        let remaining_size = (available_size - tracks_size(&sizes, spacing)).max(0.0);
+        for (size, track) in sizes.iter_mut().zip(tracks) {
+            if let GridTrack::Fraction(fr) = track {
+                *size = remaining_size / total_fraction * fr.get();
+            }
+        }
+    }
+
+    sizes
+}
+
+/// Get the size occupied by some consecutive tracks of a grid
+fn tracks_size(sizes: &[f32], spacing: f32) -> f32 {
+    sizes.iter().sum::<f32>() + spacing * sizes.len().saturating_sub(1) as f32
+}
+
+/// Get the offsets where each track of a grid starts
+fn tracks_offsets(sizes: &[f32], spacing: f32) -> Vec<f32> {
+    sizes
+        .iter()
+        .scan(0.0, |offset, size| {
+            let track_offset = *offset;
+            *offset += size + spacing;
+            Some(track_offset)
+        })
+        .collect()
+}
+
+/// Fit the area of a Node to the size of its content in the axis where the size is not specified
+fn fit_inner_sizes(
+    parent_node: &Node,
+    mode: &mut MeasureMode,
+    available_area: &mut Area,
+    inner_sizes: &mut Size2D,
+    content_size: Size2D,
+) {
+    if let MeasureMode::ParentIsNotCached {
+        area,
+        inner_area,
+        width_inner_sized,
+        height_inner_sized,
+    } = mode
+    {
+        *inner_sizes = content_size;
+
+        if *width_inner_sized {
+            area.size.width = area.size.width.max(
+                inner_sizes.width
+                    + parent_node.padding.horizontal()
+                    + parent_node.margin.horizontal(),
+            );
+            inner_area.size.width = area.size.width
+                - parent_node.padding.horizontal()
+                - parent_node.margin.horizontal();
+            available_area.size.width = inner_area.size.width;
+        }
+
+        if *height_inner_sized {
+            area.size.height = area.size.height.max(
+                inner_sizes.height + parent_node.padding.vertical() + parent_node.margin.vertical(),
+            );
+            inner_area.size.height =
+                area.size.height - parent_node.padding.vertical() - parent_node.margin.vertical();
+            available_area.size.height = inner_area.size.height;
+        }
+    }
+}
+
+/// Get the offset needed to align some content in the given free space
+fn alignment_offset(alignment: &Alignment, free_space: f32) -> f32 {
+    match alignment {
+        Alignment::Center => free_space / 2.0,
+        Alignment::End => free_space,
+        _ => 0.0,
+    }
+}
+
+/// Get the offset needed to align some elements in the given free space,
+/// and the extra space to leave after each one of them
+fn alignment_offsets(alignment: &Alignment, free_space: f32, elements: usize) -> (f32, f32) {
+    if !alignment.is_spaced() {
+        return (alignment_offset(alignment, free_space), 0.0);
+    }
+
+    // Overflowing elements are not spaced
+    if free_space <= 0.0 || elements == 0 {
+        return (0.0, 0.0);
+    }
+
+    match alignment {
+        Alignment::SpaceBetween if elements > 1 => (0.0, free_space / (elements - 1) as f32),
+        Alignment::SpaceAround => {
+            let gap = free_space / elements as f32;
+            (gap / 2.0, gap)
+        }
+        Alignment::SpaceEvenly => {
+            let gap = free_space / (elements + 1) as f32;
+            (gap, gap)
+        }
+        _ => (0.0, 0.0),
+    }
+}
+
+/// Get the size of the Main axis for the given direction
+fn main_axis_size(direction: &DirectionMode, size: &Size2D) -> f32 {
+    match direction {
+        DirectionMode::Vertical => size.height,
+        DirectionMode::Horizontal => size.width,
+    }
+}
+
+/// Get the size of the Cross axis for the given direction
+fn cross_axis_size(direction: &DirectionMode, size: &Size2D) -> f32 {
+    match direction {
+        DirectionMode::Vertical => size.width,
+        DirectionMode::Horizontal => size.height,
+    }
+}
+
+/// Create a size given the sizes of its Main and Cross axis for the given direction
+fn size_from_axis(direction: &DirectionMode, main_size: f32, cross_size: f32) -> Size2D {
+    match direction {
+        DirectionMode::Vertical => Size2D::new(cross_size, main_size),
+        DirectionMode::Horizontal => Size2D::new(main_size, cross_size),
+    }
+}
+
+/// Share the available space of the Main axis between the flex children of a Node by their weights.
+/// Children whose minimum or maximum size prevent them from taking their share get their constrained
+/// size instead and the rest of the space is shared again between the others.
+fn resolve_flex_sizes<Key: NodeKey>(
+    parent_node: &Node,
+    children: &[(Key, Node)],
+    flex_grows: &FxHashMap<Key, f32>,
+    parent_size: f32,
+    mut available_size: f32,
+    root_size: f32,
+) -> FxHashMap<Key, f32> {
+    let mut flex_sizes = FxHashMap::default();
+
+    let mut pending_children = children
+        .iter()
+        .filter(|(child_id, _)| flex_grows.contains_key(child_id))
+        .collect::<Vec<_>>();
+
+    while !pending_children.is_empty() {
+        let total_grow = pending_children
+            .iter()
+            .map(|(child_id, _)| flex_grows[child_id])
+            .sum::<f32>();
+        let mut constrained_size = 0.0;
+        let mut any_constrained = false;
+        let mut shares = Vec::with_capacity(pending_children.len());
+
+        pending_children.retain(|(child_id, child_data)| {
+            let share = if total_grow > 0.0 {
+                available_size.max(0.0) / total_grow * flex_grows[child_id]
+            } else {
+                0.0
+            };
+
+            let (size, minimum, maximum, single_margin, margin) = match parent_node.direction {
+                DirectionMode::Vertical => (
+                    &child_data.height,
+                    &child_data.minimum_height,
+                    &child_data.maximum_height,
+                    child_data.margin.top(),
+                    child_data.margin.vertical(),
+                ),
+                DirectionMode::Horizontal => (
+                    &child_data.width,
+                    &child_data.minimum_width,
+                    &child_data.maximum_width,
+                    child_data.margin.left(),
+                    child_data.margin.horizontal(),
+                ),
+            };
+
+            let final_size = size.min_max(
+                0.0,
+                parent_size,
+                share,
+                single_margin,
+                margin,
+                minimum,
+                maximum,
+                root_size,
+                Phase::Final,
+            );
+
+            if final_size != share {
+                // This child is constrained, so it keeps its size out of the shared space
+                flex_sizes.insert(*child_id, final_size);
+                constrained_size += final_size;
+                any_constrained = true;
+                false
+            } else {
+                shares.push((*child_id, share));
+                true
+            }
+        });
+
+        if !any_constrained {
+            flex_sizes.extend(shares);
+            break;
+        }
+
+        available_size -= constrained_size;
+    }
+
+    flex_sizes
+}
@@ -11,6 +11,10 @@ pub enum MeasureMode<'a> {
     ParentIsNotCached {
         area: &'a mut Area,
         inner_area: &'a mut Area,
+        /// Whether the width of the parent is decided by its inner Nodes
+        width_inner_sized: bool,
+        /// Whether the height of the parent is decided by its inner Nodes
+        height_inner_sized: bool,
     },
 }
 
@@ -29,12 +33,17 @@ impl<'a> MeasureMode<'a> {
             MeasureMode::ParentIsCached { inner_area } => OwnedMeasureMode::ParentIsCached {
                 inner_area: *inner_area.to_owned(),
             },
-            MeasureMode::ParentIsNotCached { area, inner_area } => {
-                OwnedMeasureMode::ParentIsNotCached {
-                    area: **area,
-                    inner_area: **inner_area,
-                }
-            }
+            MeasureMode::ParentIsNotCached {
+                area,
+                inner_area,
+                width_inner_sized,
+                height_inner_sized,
+            } => OwnedMeasureMode::ParentIsNotCached {
+                area: **area,
+                inner_area: **inner_area,
+                width_inner_sized: *width_inner_sized,
+                height_inner_sized: *height_inner_sized,
+            },
         }
     }
 
@@ -48,6 +57,8 @@ impl<'a> MeasureMode<'a> {
         parent_node: &Node,
         alignment_direction: AlignmentDirection,
         available_area: &mut Area,
+        // Whether any inner Node is aligned in the Cross axis
+        is_cross_aligned: bool,
     ) {
         struct NodeData<'a> {
             pub inner_origin: &'a mut f32,
@@ -65,16 +76,22 @@ impl<'a> MeasureMode<'a> {
         let (is_vertical_not_start, is_horizontal_not_start) = match parent_node.direction {
             DirectionMode::Vertical => (
                 parent_node.main_alignment.is_not_start(),
-                parent_node.cross_alignment.is_not_start() || parent_node.content.is_fit(),
+                is_cross_aligned || parent_node.content.is_fit(),
             ),
             DirectionMode::Horizontal => (
-                parent_node.cross_alignment.is_not_start() || parent_node.content.is_fit(),
+                is_cross_aligned || parent_node.content.is_fit(),
                 parent_node.main_alignment.is_not_start(),
             ),
         };
-        let params = if let MeasureMode::ParentIsNotCached { area, inner_area } = self {
+        let params = if let MeasureMode::ParentIsNotCached {
+            area,
+            inner_area,
+            width_inner_sized,
+            height_inner_sized,
+        } = self
+        {
             match axis {
-                AlignAxis::Height if parent_node.height.inner_sized() && is_vertical_not_start => {
+                AlignAxis::Height if *height_inner_sized && is_vertical_not_start => {
                     Some(NodeData {
                         inner_origin: &mut inner_area.origin.y,
                         inner_size: &mut inner_area.size.height,
@@ -87,7 +104,7 @@ impl<'a> MeasureMode<'a> {
                         available_size: &mut available_area.size.height,
                     })
                 }
-                AlignAxis::Width if parent_node.width.inner_sized() && is_horizontal_not_start => {
+                AlignAxis::Width if *width_inner_sized && is_horizontal_not_start => {
                     Some(NodeData {
                         inner_origin: &mut inner_area.origin.x,
                         inner_size: &mut inner_area.size.width,
@@ -146,12 +163,18 @@ impl<'a> MeasureMode<'a> {
                 available_area.origin.x = content_area.max_x();
                 available_area.size.width -= content_area.size.width;
 
-                if let MeasureMode::ParentIsNotCached { area, inner_area } = self {
+                if let MeasureMode::ParentIsNotCached {
+                    area,
+                    inner_area,
+                    width_inner_sized,
+                    height_inner_sized,
+                } = self
+                {
                     inner_sizes.height = content_area.height().max(inner_sizes.height);
                     inner_sizes.width += content_area.width();
 
                     // Keep the biggest height
-                    if parent_node.height.inner_sized() {
+                    if *height_inner_sized {
                         area.size.height = area.size.height.max(
                             content_area.size.height
                                 + parent_node.padding.vertical()
@@ -164,7 +187,7 @@ impl<'a> MeasureMode<'a> {
                     }
 
                     // Accumulate width
-                    if parent_node.width.inner_sized() {
+                    if *width_inner_sized {
                         area.size.width += content_area.size.width;
                     }
                 }
@@ -174,12 +197,18 @@ impl<'a> MeasureMode<'a> {
                 available_area.origin.y = content_area.max_y();
                 available_area.size.height -= content_area.size.height;
 
-                if let MeasureMode::ParentIsNotCached { area, inner_area } = self {
+                if let MeasureMode::ParentIsNotCached {
+                    area,
+                    inner_area,
+                    width_inner_sized,
+                    height_inner_sized,
+                } = self
+                {
                     inner_sizes.width = content_area.width().max(inner_sizes.width);
                     inner_sizes.height += content_area.height();
 
                     // Keep the biggest width
-                    if parent_node.width.inner_sized() {
+                    if *width_inner_sized {
                         area.size.width = area.size.width.max(
                             content_area.size.width
                                 + parent_node.padding.horizontal()
@@ -192,7 +221,7 @@ impl<'a> MeasureMode<'a> {
                     }
 
                     // Accumulate height
-                    if parent_node.height.inner_sized() {
+                    if *height_inner_sized {
                         area.size.height += content_area.size.height;
                     }
                 }
@@ -204,17 +233,32 @@ impl<'a> MeasureMode<'a> {
 /// Just an owned version of [MeasureMode]
 #[derive(Debug)]
 pub enum OwnedMeasureMode {
-    ParentIsCached { inner_area: Area },
-    ParentIsNotCached { area: Area, inner_area: Area },
+    ParentIsCached {
+        inner_area: Area,
+    },
+    ParentIsNotCached {
+        area: Area,
+        inner_area: Area,
+        width_inner_sized: bool,
+        height_inner_sized: bool,
+    },
 }
 
 impl OwnedMeasureMode {
     pub fn to_mut(&mut self) -> MeasureMode<'_> {
         match self {
             Self::ParentIsCached { inner_area } => MeasureMode::ParentIsCached { inner_area },
-            Self::ParentIsNotCached { area, inner_area } => {
-                MeasureMode::ParentIsNotCached { area, inner_area }
-            }
+            Self::ParentIsNotCached {
+                area,
+                inner_area,
+                width_inner_sized,
+                height_inner_sized,
+            } => MeasureMode::ParentIsNotCached {
+                area,
+                inner_area,
+                width_inner_sized: *width_inner_sized,
+                height_inner_sized: *height_inner_sized,
+            },
         }
     }
 }
@@ -5,8 +5,10 @@ use crate::{
     direction::DirectionMode,
     gaps::Gaps,
     geometry::Length,
+    grid::{GridPlacement, GridTrack},
     prelude::{Content, Position},
     size::Size,
+    wrap_content::WrapContent,
 };
 
 /// Node layout configuration
@@ -28,6 +30,12 @@ pub struct Node {
     pub main_alignment: Alignment,
     pub cross_alignment: Alignment,
 
+    /// Alignment of this Node in the Cross axis of its parent, overrides the parent's `cross_alignment`
+    pub align_self: Option<Alignment>,
+
+    /// Ratio between the width and the height, used to size an unspecified axis
+    pub aspect_ratio: Option<f32>,
+
     /// Inner padding
     pub padding: Gaps,
 
@@ -46,6 +54,20 @@ pub struct Node {
 
     pub content: Content,
 
+    /// Whether the inner Nodes will be wrapped into new lines when they don't fit in the main axis
+    pub wrap_content: WrapContent,
+
+    /// Spacing between the wrapped inner Nodes or the tracks of a grid
+    pub spacing: Gaps,
+
+    /// Tracks of the grid in which the inner Nodes will be placed
+    pub grid_columns: Vec<GridTrack>,
+    pub grid_rows: Vec<GridTrack>,
+
+    /// Placement of this Node in the grid of its parent
+    pub grid_column: GridPlacement,
+    pub grid_row: GridPlacement,
+
     /// A Node might depend on inner sizes but have a fixed position, like scroll views.
     pub has_layout_references: bool,
 
@@ -178,6 +200,64 @@ impl Node {
         }
     }
 
+    /// Construct a new Node given a size and the tracks of a grid
+    pub fn from_size_and_grid(
+        width: Size,
+        height: Size,
+        grid_columns: Vec<GridTrack>,
+        grid_rows: Vec<GridTrack>,
+        spacing: Gaps,
+    ) -> Self {
+        Self {
+            width,
+            height,
+            grid_columns,
+            grid_rows,
+            spacing,
+            ..Default::default()
+        }
+    }
+
+    /// Construct a new Node given a size and a placement in the grid of its parent
+    pub fn from_size_and_grid_placement(
+        width: Size,
+        height: Size,
+        grid_column: GridPlacement,
+        grid_row: GridPlacement,
+    ) -> Self {
+        Self {
+            width,
+            height,
+            grid_column,
+            grid_row,
+            ..Default::default()
+        }
+    }
+
+    /// Get the alignment in the Cross axis of the given inner Node
+    pub fn inner_cross_alignment<'a>(&'a self, inner_node: &'a Node) -> &'a Alignment {
+        inner_node
+            .align_self
+            .as_ref()
+            .unwrap_or(&self.cross_alignment)
+    }
+
+    /// Whether the inner Nodes are placed in a grid
+    pub fn is_grid(&self) -> bool {
+        !self.grid_columns.is_empty() || !self.grid_rows.is_empty()
+    }
+
+    /// Get the flex weight of this Node in the main axis of the given direction
+    pub fn flex_grow(&self, direction: &DirectionMode) -> Option<f32> {
+        if self.position.is_absolute() {
+            return None;
+        }
+        match direction {
+            DirectionMode::Vertical => self.height.flex_grow(),
+            DirectionMode::Horizontal => self.width.flex_grow(),
+        }
+    }
+
     /// Has properties that depend on the inner Nodes?
     pub fn does_depend_on_inner(&self) -> bool {
         self.width.inner_sized()
@@ -186,5 +266,7 @@ impl Node {
             || self.cross_alignment.is_not_start()
             || self.main_alignment.is_not_start()
             || self.contains_text
+            || self.wrap_content.is_wrap()
+            || self.is_grid()
     }
 }
@@ -0,0 +1,252 @@
+use std::fmt::{self, Display};
+
+use rustc_hash::FxHashMap;
+
+use crate::{
+    dom_adapter::{DOMAdapter, NodeKey},
+    gaps::Gaps,
+    geometry::{Area, Point2D, Size2D},
+    torin::Torin,
+};
+
+/// Layout results of a Node, identified by its path in the tree
+#[derive(Debug, Clone, PartialEq)]
+pub struct NodeSnapshot {
+    /// Position of the Node and its ancestors in their parents, starting from the root, e.g `0/2/1`
+    pub path: String,
+
+    pub area: Area,
+
+    pub inner_area: Area,
+
+    pub margin: Gaps,
+}
+
+impl Display for NodeSnapshot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} area={},{},{},{} inner_area={},{},{},{} margin={},{},{},{}",
+            self.path,
+            self.area.min_x(),
+            self.area.min_y(),
+            self.area.width(),
+            self.area.height(),
+            self.inner_area.min_x(),
+            self.inner_area.min_y(),
+            self.inner_area.width(),
+            self.inner_area.height(),
+            self.margin.top(),
+            self.margin.right(),
+            self.margin.bottom(),
+            self.margin.left(),
+        )
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseLayoutSnapshotError {
+    /// Line of the snapshot that could not be parsed, starting from 1
+    pub line: usize,
+}
+
+/// Layout results of a whole tree, in a stable text format that can be stored and compared
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct LayoutSnapshot {
+    pub nodes: Vec<NodeSnapshot>,
+}
+
+impl LayoutSnapshot {
+    /// Take a snapshot of the cached layout results of the given Node and all its descendants
+    pub fn new<Key: NodeKey>(
+        layout: &Torin<Key>,
+        root_id: Key,
+        dom_adapter: &mut impl DOMAdapter<Key>,
+    ) -> Self {
+        let mut snapshot = Self::default();
+        snapshot.add_node(layout, root_id, "0".to_string(), dom_adapter);
+        snapshot
+    }
+
+    fn add_node<Key: NodeKey>(
+        &mut self,
+        layout: &Torin<Key>,
+        node_id: Key,
+        path: String,
+        dom_adapter: &mut impl DOMAdapter<Key>,
+    ) {
+        if let Some(layout_node) = layout.get(node_id) {
+            self.nodes.push(NodeSnapshot {
+                path: path.clone(),
+                area: layout_node.area,
+                inner_area: layout_node.inner_area,
+                margin: layout_node.margin,
+            });
+        }
+
+        for (i, child_id) in dom_adapter.children_of(&node_id).into_iter().enumerate() {
+            self.add_node(layout, child_id, format!("{path}/{i}"), dom_adapter);
+        }
+    }
+
+    /// Parse a snapshot previously created with its [Display] implementation
+    pub fn parse(value: &str) -> Result<Self, ParseLayoutSnapshotError> {
+        let nodes = value
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(i, line)| parse_node(line).ok_or(ParseLayoutSnapshotError { line: i + 1 }))
+            .collect::<Result<Vec<NodeSnapshot>, ParseLayoutSnapshotError>>()?;
+
+        Ok(Self { nodes })
+    }
+
+    /// Get the snapshot of the Node in the given path
+    pub fn get(&self, path: &str) -> Option<&NodeSnapshot> {
+        self.nodes.iter().find(|node| node.path == path)
+    }
+
+    /// Get the Nodes that were added, removed, moved or resized in the given snapshot
+    pub fn diff(&self, other: &LayoutSnapshot) -> Vec<LayoutDiff> {
+        let before = self
+            .nodes
+            .iter()
+            .map(|node| (node.path.as_str(), node))
+            .collect::<FxHashMap<&str, &NodeSnapshot>>();
+        let after = other
+            .nodes
+            .iter()
+            .map(|node| (node.path.as_str(), node))
+            .collect::<FxHashMap<&str, &NodeSnapshot>>();
+
+        let mut diffs = Vec::new();
+
+        for node in &other.nodes {
+            match before.get(node.path.as_str()) {
+                None => diffs.push(LayoutDiff::Added(node.clone())),
+                Some(before) if *before != node => diffs.push(LayoutDiff::Changed {
+                    before: (*before).clone(),
+                    after: node.clone(),
+                }),
+                _ => {}
+            }
+        }
+
+        for node in &self.nodes {
+            if !after.contains_key(node.path.as_str()) {
+                diffs.push(LayoutDiff::Removed(node.clone()));
+            }
+        }
+
+        diffs
+    }
+}
+
+impl Display for LayoutSnapshot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for node in &self.nodes {
+            writeln!(f, "{node}")?;
+        }
+        Ok(())
+    }
+}
+
+fn parse_values<const N: usize>(value: &str) -> Option<[f32; N]> {
+    let mut values = [0.0; N];
+    let mut parts = value.split(',');
+    for value in values.iter_mut() {
+        *value = parts.next()?.parse().ok()?;
+    }
+    parts.next().is_none().then_some(values)
+}
+
+fn parse_area(value: &str) -> Option<Area> {
+    let [x, y, width, height] = parse_values(value)?;
+    Some(Area::new(Point2D::new(x, y), Size2D::new(width, height)))
+}
+
+fn parse_node(line: &str) -> Option<NodeSnapshot> {
+    let mut parts = line.split_whitespace();
+    let path = parts.next()?.to_string();
+    let area = parse_area(parts.next()?.strip_prefix("area=")?)?;
+    let inner_area = parse_area(parts.next()?.strip_prefix("inner_area=")?)?;
+    let [top, right, bottom, left] = parse_values(parts.next()?.strip_prefix("margin=")?)?;
+
+    if parts.next().is_some() {
+        return None;
+    }
+
+    Some(NodeSnapshot {
+        path,
+        area,
+        inner_area,
+        margin: Gaps::new(top, right, bottom, left),
+    })
+}
+
+/// Difference of a Node between two [LayoutSnapshot]s
+#[derive(Debug, Clone, PartialEq)]
+pub enum LayoutDiff {
+    Added(NodeSnapshot),
+    Removed(NodeSnapshot),
+    Changed {
+        before: NodeSnapshot,
+        after: NodeSnapshot,
+    },
+}
+
+impl LayoutDiff {
+    /// Path of the Node
+    pub fn path(&self) -> &str {
+        match self {
+            Self::Added(node) | Self::Removed(node) => &node.path,
+            Self::Changed { after, .. } => &after.path,
+        }
+    }
+
+    /// Whether the Node was moved
+    pub fn moved(&self) -> bool {
+        matches!(self, Self::Changed { before, after } if before.area.origin != after.area.origin)
+    }
+
+    /// Whether the Node was resized
+    pub fn resized(&self) -> bool {
+        matches!(self, Self::Changed { before, after } if before.area.size != after.area.size)
+    }
+}
+
+impl Display for LayoutDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Added(node) => write!(f, "+ {node}"),
+            Self::Removed(node) => write!(f, "- {node}"),
+            Self::Changed { before, after } => {
+                write!(f, "~ {}", after.path)?;
+                if self.moved() {
+                    write!(
+                        f,
+                        " moved from {},{} to {},{}",
+                        before.area.min_x(),
+                        before.area.min_y(),
+                        after.area.min_x(),
+                        after.area.min_y()
+                    )?;
+                }
+                if self.resized() {
+                    write!(
+                        f,
+                        " resized from {}x{} to {}x{}",
+                        before.area.width(),
+                        before.area.height(),
+                        after.area.width(),
+                        after.area.height()
+                    )?;
+                }
+                if !self.moved() && !self.resized() {
+                    write!(f, " changed its inner area or margin")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
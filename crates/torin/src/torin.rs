@@ -10,6 +10,7 @@ use crate::{
     geometry::{Area, Size2D},
     measure::{measure_node, Phase},
     prelude::{AreaModel, Gaps},
+    snapshot::LayoutSnapshot,
 };
 
 pub struct LayoutMetadata {
@@ -59,6 +60,9 @@ pub struct Torin<Key: NodeKey> {
     /// Invalid registered nodes since previous layout measurement
     pub dirty: FxHashSet<Key>,
 
+    /// Nodes that contain dirty Nodes, so their cached measurements can't be fully reused
+    pub(crate) dirty_ancestors: FxHashSet<Key>,
+
     /// Best Root node candidate from where to start measuring
     pub root_node_candidate: RootNodeCandidate<Key>,
 }
@@ -75,6 +79,7 @@ impl<Key: NodeKey> Torin<Key> {
         Self {
             results: HashMap::default(),
             dirty: FxHashSet::default(),
+            dirty_ancestors: FxHashSet::default(),
             root_node_candidate: RootNodeCandidate::None,
         }
     }
@@ -88,6 +93,7 @@ impl<Key: NodeKey> Torin<Key> {
         self.root_node_candidate = RootNodeCandidate::None;
         self.results.clear();
         self.dirty.clear();
+        self.dirty_ancestors.clear();
     }
 
     /// Read the HashSet of dirty nodes
@@ -163,24 +169,29 @@ impl<Key: NodeKey> Torin<Key> {
             let parent = dom_adapter.get_node(&parent_id);
 
             if let Some(parent) = parent {
-                if parent.does_depend_on_inner() {
+                let parent_children = dom_adapter.children_of(&parent_id);
+
+                // Flex children depend on the sizes of all their siblings
+                let has_flex_children = parent_children.iter().any(|child_id| {
+                    dom_adapter
+                        .get_node(child_id)
+                        .and_then(|child| child.flex_grow(&parent.direction))
+                        .is_some()
+                });
+
+                // Self-aligned Nodes are positioned by their parent given their size
+                let is_self_aligned = dom_adapter
+                    .get_node(&node_id)
+                    .is_some_and(|node| node.align_self.is_some());
+
+                if parent.does_depend_on_inner() || has_flex_children || is_self_aligned {
                     // Mark parent if it depends on it's inner children
                     self.check_dirty_dependants(parent_id, dom_adapter, true);
                 } else {
-                    let parent_children = dom_adapter.children_of(&parent_id);
                     let multiple_children = parent_children.len() > 1;
 
-                    let mut found_node = false;
-                    for child_id in dom_adapter.children_of(&parent_id) {
-                        if found_node {
-                            self.safe_invalidate(child_id, dom_adapter);
-                        }
-                        if child_id == node_id {
-                            found_node = true;
-                        }
-                    }
-
-                    // Try using the node's parent as root candidate if it has multiple children
+                    // Try using the node's parent as root candidate if it has multiple children,
+                    // the siblings are only measured again if their constraints change
                     if multiple_children {
                         self.root_node_candidate
                             .propose_new_candidate(&parent_id, dom_adapter);
@@ -235,6 +246,7 @@ impl<Key: NodeKey> Torin<Key> {
                 inner_sizes: Size2D::default(),
                 margin: Gaps::default(),
                 data: None,
+                constraints: None,
             });
         let root = dom_adapter.get_node(&root_id).unwrap();
         let root_height = dom_adapter.height(&root_id).unwrap();
@@ -248,17 +260,37 @@ impl<Key: NodeKey> Torin<Key> {
 
         let metadata = LayoutMetadata { root_area };
 
+        let mut parent_area = layout_node.inner_area;
         let mut available_area = layout_node.inner_area;
         if let Some(root_parent_id) = root_parent_id {
             let root_parent = dom_adapter.get_node(&root_parent_id).unwrap();
             available_area.move_with_offsets(&root_parent.offset_x, &root_parent.offset_y);
+
+            // Measure the Root again under the same constraints as it was last time, as its previous siblings are not measured
+            if let Some(constraints) = self.get(root_id).and_then(|root| root.constraints.clone()) {
+                if constraints.root_area == root_area {
+                    parent_area = constraints.parent_area;
+                    available_area = constraints.available_parent_area;
+                }
+            }
+        }
+
+        // Mark the Nodes containing dirty Nodes
+        for dirty in &self.dirty {
+            let mut parent_id = dom_adapter.parent_of(dirty);
+            while let Some(id) = parent_id {
+                if !self.dirty_ancestors.insert(id) {
+                    break;
+                }
+                parent_id = dom_adapter.parent_of(&id);
+            }
         }
 
         let (root_revalidated, root_layout_node) = measure_node(
             root_id,
             &root,
             self,
-            &layout_node.inner_area,
+            &parent_area,
             &available_area,
             measurer,
             true,
@@ -279,9 +311,15 @@ impl<Key: NodeKey> Torin<Key> {
         }
 
         self.dirty.clear();
+        self.dirty_ancestors.clear();
         self.root_node_candidate = RootNodeCandidate::None;
     }
 
+    /// Take a [LayoutSnapshot] of the given Node and all its descendants
+    pub fn snapshot(&self, root_id: Key, dom_adapter: &mut impl DOMAdapter<Key>) -> LayoutSnapshot {
+        LayoutSnapshot::new(self, root_id, dom_adapter)
+    }
+
     /// Get the layout_node of a Node
     pub fn get(&self, node_id: Key) -> Option<&LayoutNode> {
         self.results.get(&node_id)
@@ -4,6 +4,9 @@ pub enum Alignment {
     Start,
     Center,
     End,
+    SpaceBetween,
+    SpaceAround,
+    SpaceEvenly,
 }
 
 impl Alignment {
@@ -11,11 +14,22 @@ impl Alignment {
         *self != Self::Start
     }
 
+    /// Whether this alignment distributes the free space between the elements
+    pub fn is_spaced(&self) -> bool {
+        matches!(
+            self,
+            Self::SpaceBetween | Self::SpaceAround | Self::SpaceEvenly
+        )
+    }
+
     pub fn pretty(&self) -> String {
         match self {
             Alignment::Start => "start".to_string(),
             Alignment::Center => "center".to_string(),
             Alignment::End => "end".to_string(),
+            Alignment::SpaceBetween => "space-between".to_string(),
+            Alignment::SpaceAround => "space-around".to_string(),
+            Alignment::SpaceEvenly => "space-evenly".to_string(),
         }
     }
 }
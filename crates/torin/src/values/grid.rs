@@ -0,0 +1,63 @@
+use crate::{geometry::Length, scaled::Scaled};
+
+/// Size of a column or a row of a grid
+#[derive(PartialEq, Clone, Debug, Default)]
+pub enum GridTrack {
+    /// Use the biggest size of the Nodes in this track
+    #[default]
+    Auto,
+    Pixels(Length),
+    Percentage(Length),
+    /// Share of the remaining space
+    Fraction(Length),
+}
+
+impl GridTrack {
+    pub fn pretty(&self) -> String {
+        match self {
+            GridTrack::Auto => "auto".to_string(),
+            GridTrack::Pixels(s) => format!("{}", s.get()),
+            GridTrack::Percentage(p) => format!("{}%", p.get()),
+            GridTrack::Fraction(f) => format!("{}fr", f.get()),
+        }
+    }
+}
+
+impl Scaled for GridTrack {
+    fn scale(&mut self, scale_factor: f32) {
+        if let GridTrack::Pixels(s) = self {
+            *s *= scale_factor;
+        }
+    }
+}
+
+/// Placement of a Node in a column or row of a grid
+#[derive(PartialEq, Clone, Debug)]
+pub struct GridPlacement {
+    /// Index of the first track, automatically placed if not specified
+    pub start: Option<usize>,
+    /// Number of tracks occupied
+    pub span: usize,
+}
+
+impl Default for GridPlacement {
+    fn default() -> Self {
+        Self {
+            start: None,
+            span: 1,
+        }
+    }
+}
+
+impl GridPlacement {
+    pub fn new(start: Option<usize>, span: usize) -> Self {
+        Self { start, span }
+    }
+
+    pub fn pretty(&self) -> String {
+        match self.start {
+            Some(start) => format!("{} / span {}", start + 1, self.span),
+            None => format!("span {}", self.span),
+        }
+    }
+}
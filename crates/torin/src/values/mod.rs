@@ -2,14 +2,18 @@ pub mod alignment;
 pub mod content;
 pub mod direction;
 pub mod gaps;
+pub mod grid;
 pub mod position;
 pub mod size;
+pub mod wrap_content;
 
 pub mod prelude {
     pub use crate::alignment::*;
     pub use crate::content::*;
     pub use crate::direction::*;
     pub use crate::gaps::*;
+    pub use crate::grid::*;
     pub use crate::position::*;
     pub use crate::size::*;
+    pub use crate::wrap_content::*;
 }
@@ -14,6 +14,7 @@ pub enum Size {
     Pixels(Length),
     RootPercentage(Length),
     DynamicCalculations(Box<Vec<DynamicCalculation>>),
+    Flex(Length),
 }
 
 impl Default for Size {
@@ -27,6 +28,14 @@ impl Size {
         matches!(self, Self::Inner | Self::FillMinimum)
     }
 
+    /// Whether this size depends on the space available in the parent
+    pub fn depends_on_available(&self) -> bool {
+        matches!(
+            self,
+            Self::Fill | Self::FillMinimum | Self::Flex(_) | Self::Percentage(_)
+        )
+    }
+
     pub fn pretty(&self) -> String {
         match self {
             Size::Inner => "auto".to_string(),
@@ -43,6 +52,15 @@ impl Size {
             Size::Fill => "fill".to_string(),
             Size::FillMinimum => "fill-min".to_string(),
             Size::RootPercentage(p) => format!("{}% of root", p.get()),
+            Size::Flex(f) => format!("flex({})", f.get()),
+        }
+    }
+
+    /// Get the flex weight of this size, if it has one
+    pub fn flex_grow(&self) -> Option<f32> {
+        match self {
+            Self::Flex(f) => Some(f.get()),
+            _ => None,
         }
     }
 
@@ -69,6 +87,14 @@ impl Size {
                 }
             }
             Size::RootPercentage(per) => Some(root_value / 100.0 * per.get()),
+            Size::Flex(_) => {
+                // The available area of flex Nodes is only decided once their siblings were measured
+                if phase == Phase::Initial {
+                    None
+                } else {
+                    Some(available_parent_value)
+                }
+            }
             _ => None,
         }
     }
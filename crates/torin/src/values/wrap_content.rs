@@ -0,0 +1,19 @@
+#[derive(PartialEq, Clone, Debug, Default)]
+pub enum WrapContent {
+    #[default]
+    NoWrap,
+    Wrap,
+}
+
+impl WrapContent {
+    pub fn is_wrap(&self) -> bool {
+        self == &Self::Wrap
+    }
+
+    pub fn pretty(&self) -> String {
+        match self {
+            WrapContent::NoWrap => "no-wrap".to_string(),
+            WrapContent::Wrap => "wrap".to_string(),
+        }
+    }
+}
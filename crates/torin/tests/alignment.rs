@@ -330,3 +330,281 @@ pub fn nested_unsized_alignment() {
         Rect::new(Point2D::new(50.0, 50.0), Size2D::new(100.0, 100.0)),
     );
 }
+
+#[test]
+pub fn space_between_alignment() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut mocked_dom = TestingDOM::default();
+    mocked_dom.add(
+        0,
+        None,
+        vec![1, 2, 3],
+        Node::from_size_and_alignments_and_direction(
+            Size::Pixels(Length::new(300.0)),
+            Size::Pixels(Length::new(100.0)),
+            Alignment::SpaceBetween,
+            Alignment::Center,
+            DirectionMode::Horizontal,
+        ),
+    );
+    for i in 1..=3 {
+        mocked_dom.add(
+            i,
+            Some(0),
+            vec![],
+            Node::from_size_and_direction(
+                Size::Pixels(Length::new(50.0)),
+                Size::Pixels(Length::new(50.0)),
+                DirectionMode::Vertical,
+            ),
+        );
+    }
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    assert_eq!(
+        layout.get(1).unwrap().area,
+        Rect::new(Point2D::new(0.0, 25.0), Size2D::new(50.0, 50.0)),
+    );
+
+    assert_eq!(
+        layout.get(2).unwrap().area,
+        Rect::new(Point2D::new(125.0, 25.0), Size2D::new(50.0, 50.0)),
+    );
+
+    assert_eq!(
+        layout.get(3).unwrap().area,
+        Rect::new(Point2D::new(250.0, 25.0), Size2D::new(50.0, 50.0)),
+    );
+}
+
+#[test]
+pub fn space_around_alignment() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut mocked_dom = TestingDOM::default();
+    mocked_dom.add(
+        0,
+        None,
+        vec![1, 2, 3],
+        Node::from_size_and_alignments_and_direction(
+            Size::Pixels(Length::new(100.0)),
+            Size::Pixels(Length::new(300.0)),
+            Alignment::SpaceAround,
+            Alignment::Start,
+            DirectionMode::Vertical,
+        ),
+    );
+    for i in 1..=3 {
+        mocked_dom.add(
+            i,
+            Some(0),
+            vec![],
+            Node::from_size_and_direction(
+                Size::Pixels(Length::new(50.0)),
+                Size::Pixels(Length::new(50.0)),
+                DirectionMode::Vertical,
+            ),
+        );
+    }
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    assert_eq!(
+        layout.get(1).unwrap().area,
+        Rect::new(Point2D::new(0.0, 25.0), Size2D::new(50.0, 50.0)),
+    );
+
+    assert_eq!(
+        layout.get(2).unwrap().area,
+        Rect::new(Point2D::new(0.0, 125.0), Size2D::new(50.0, 50.0)),
+    );
+
+    assert_eq!(
+        layout.get(3).unwrap().area,
+        Rect::new(Point2D::new(0.0, 225.0), Size2D::new(50.0, 50.0)),
+    );
+}
+
+#[test]
+pub fn space_evenly_alignment() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut mocked_dom = TestingDOM::default();
+    mocked_dom.add(
+        0,
+        None,
+        vec![1, 2],
+        Node::from_size_and_alignments_and_direction(
+            Size::Pixels(Length::new(300.0)),
+            Size::Pixels(Length::new(100.0)),
+            Alignment::SpaceEvenly,
+            Alignment::Start,
+            DirectionMode::Horizontal,
+        ),
+    );
+    for i in 1..=2 {
+        mocked_dom.add(
+            i,
+            Some(0),
+            vec![],
+            Node::from_size_and_direction(
+                Size::Pixels(Length::new(60.0)),
+                Size::Pixels(Length::new(50.0)),
+                DirectionMode::Vertical,
+            ),
+        );
+    }
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    assert_eq!(
+        layout.get(1).unwrap().area,
+        Rect::new(Point2D::new(60.0, 0.0), Size2D::new(60.0, 50.0)),
+    );
+
+    assert_eq!(
+        layout.get(2).unwrap().area,
+        Rect::new(Point2D::new(180.0, 0.0), Size2D::new(60.0, 50.0)),
+    );
+}
+
+#[test]
+pub fn align_self() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut mocked_dom = TestingDOM::default();
+    mocked_dom.add(
+        0,
+        None,
+        vec![1, 2, 3],
+        Node::from_size_and_alignments_and_direction(
+            Size::Pixels(Length::new(200.0)),
+            Size::Pixels(Length::new(200.0)),
+            Alignment::Start,
+            Alignment::Center,
+            DirectionMode::Vertical,
+        ),
+    );
+    mocked_dom.add(
+        1,
+        Some(0),
+        vec![],
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(50.0)),
+            Size::Pixels(Length::new(50.0)),
+            DirectionMode::Vertical,
+        ),
+    );
+    mocked_dom.add(
+        2,
+        Some(0),
+        vec![],
+        Node {
+            width: Size::Pixels(Length::new(50.0)),
+            height: Size::Pixels(Length::new(50.0)),
+            align_self: Some(Alignment::End),
+            ..Default::default()
+        },
+    );
+    mocked_dom.add(
+        3,
+        Some(0),
+        vec![],
+        Node {
+            width: Size::Pixels(Length::new(50.0)),
+            height: Size::Pixels(Length::new(50.0)),
+            align_self: Some(Alignment::Start),
+            ..Default::default()
+        },
+    );
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    assert_eq!(
+        layout.get(1).unwrap().area,
+        Rect::new(Point2D::new(75.0, 0.0), Size2D::new(50.0, 50.0)),
+    );
+
+    assert_eq!(
+        layout.get(2).unwrap().area,
+        Rect::new(Point2D::new(150.0, 50.0), Size2D::new(50.0, 50.0)),
+    );
+
+    assert_eq!(
+        layout.get(3).unwrap().area,
+        Rect::new(Point2D::new(0.0, 100.0), Size2D::new(50.0, 50.0)),
+    );
+}
+
+#[test]
+pub fn unsized_align_self() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut mocked_dom = TestingDOM::default();
+    mocked_dom.add(
+        0,
+        None,
+        vec![1, 2],
+        Node::from_size_and_direction(Size::Inner, Size::Inner, DirectionMode::Vertical),
+    );
+    mocked_dom.add(
+        1,
+        Some(0),
+        vec![],
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(200.0)),
+            Size::Pixels(Length::new(50.0)),
+            DirectionMode::Vertical,
+        ),
+    );
+    mocked_dom.add(
+        2,
+        Some(0),
+        vec![],
+        Node {
+            width: Size::Pixels(Length::new(50.0)),
+            height: Size::Pixels(Length::new(50.0)),
+            align_self: Some(Alignment::End),
+            ..Default::default()
+        },
+    );
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    assert_eq!(
+        layout.get(0).unwrap().area,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(200.0, 100.0)),
+    );
+
+    assert_eq!(
+        layout.get(2).unwrap().area,
+        Rect::new(Point2D::new(150.0, 50.0), Size2D::new(50.0, 50.0)),
+    );
+}
@@ -0,0 +1,156 @@
+use torin::{prelude::*, test_utils::*};
+
+#[test]
+pub fn aspect_ratio_from_width() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut mocked_dom = TestingDOM::default();
+    mocked_dom.add(
+        0,
+        None,
+        vec![1],
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(300.0)),
+            Size::Pixels(Length::new(300.0)),
+            DirectionMode::Vertical,
+        ),
+    );
+    mocked_dom.add(
+        1,
+        Some(0),
+        vec![],
+        Node {
+            width: Size::Percentage(Length::new(50.0)),
+            aspect_ratio: Some(2.0),
+            ..Default::default()
+        },
+    );
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    assert_eq!(
+        layout.get(1).unwrap().area,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(150.0, 75.0)),
+    );
+}
+
+#[test]
+pub fn aspect_ratio_from_height() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut mocked_dom = TestingDOM::default();
+    mocked_dom.add(
+        0,
+        None,
+        vec![1, 2],
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(300.0)),
+            Size::Pixels(Length::new(300.0)),
+            DirectionMode::Horizontal,
+        ),
+    );
+    mocked_dom.add(
+        1,
+        Some(0),
+        vec![],
+        Node {
+            height: Size::Pixels(Length::new(100.0)),
+            aspect_ratio: Some(1.5),
+            ..Default::default()
+        },
+    );
+    mocked_dom.add(
+        2,
+        Some(0),
+        vec![],
+        Node {
+            height: Size::Pixels(Length::new(100.0)),
+            margin: Gaps::new(10.0, 10.0, 10.0, 10.0),
+            aspect_ratio: Some(0.5),
+            ..Default::default()
+        },
+    );
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    assert_eq!(
+        layout.get(1).unwrap().area,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(150.0, 100.0)),
+    );
+
+    assert_eq!(
+        layout
+            .get(2)
+            .unwrap()
+            .area
+            .after_gaps(&Gaps::new(10.0, 10.0, 10.0, 10.0)),
+        Rect::new(Point2D::new(160.0, 10.0), Size2D::new(50.0, 100.0)),
+    );
+}
+
+#[test]
+pub fn aspect_ratio_with_min_max() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut mocked_dom = TestingDOM::default();
+    mocked_dom.add(
+        0,
+        None,
+        vec![1, 2],
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(1000.0)),
+            Size::Pixels(Length::new(1000.0)),
+            DirectionMode::Vertical,
+        ),
+    );
+    mocked_dom.add(
+        1,
+        Some(0),
+        vec![],
+        Node {
+            width: Size::Pixels(Length::new(400.0)),
+            maximum_height: Size::Pixels(Length::new(150.0)),
+            aspect_ratio: Some(2.0),
+            ..Default::default()
+        },
+    );
+    mocked_dom.add(
+        2,
+        Some(0),
+        vec![],
+        Node {
+            width: Size::Pixels(Length::new(500.0)),
+            maximum_width: Size::Pixels(Length::new(300.0)),
+            minimum_height: Size::Pixels(Length::new(200.0)),
+            aspect_ratio: Some(2.0),
+            ..Default::default()
+        },
+    );
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    assert_eq!(
+        layout.get(1).unwrap().area,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(400.0, 150.0)),
+    );
+
+    assert_eq!(
+        layout.get(2).unwrap().area,
+        Rect::new(Point2D::new(0.0, 150.0), Size2D::new(300.0, 200.0)),
+    );
+}
@@ -0,0 +1,162 @@
+use std::sync::Arc;
+
+use freya_native_core::SendAnyMap;
+use rustc_hash::FxHashSet;
+use torin::{prelude::*, test_utils::*};
+
+/// Keeps track of the Nodes that get measured
+#[derive(Default)]
+struct TrackingMeasurer {
+    measured: FxHashSet<usize>,
+}
+
+impl LayoutMeasurer<usize> for TrackingMeasurer {
+    fn measure(
+        &mut self,
+        node_id: usize,
+        _node: &Node,
+        _area_size: &Size2D,
+    ) -> Option<(Size2D, Arc<SendAnyMap>)> {
+        self.measured.insert(node_id);
+        None
+    }
+
+    fn should_measure_inner_children(&mut self, _node_id: usize) -> bool {
+        true
+    }
+}
+
+fn create_dom() -> TestingDOM {
+    let mut mocked_dom = TestingDOM::default();
+    mocked_dom.add(
+        0,
+        None,
+        vec![1, 2, 3],
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(1000.0)),
+            Size::Pixels(Length::new(1000.0)),
+            DirectionMode::Vertical,
+        ),
+    );
+    for i in 1..=3 {
+        mocked_dom.add(
+            i,
+            Some(0),
+            vec![i + 3],
+            Node::from_size_and_direction(
+                Size::Pixels(Length::new(100.0)),
+                Size::Pixels(Length::new(100.0)),
+                DirectionMode::Vertical,
+            ),
+        );
+        mocked_dom.add(
+            i + 3,
+            Some(i),
+            vec![],
+            Node::from_size_and_direction(
+                Size::Pixels(Length::new(50.0)),
+                Size::Pixels(Length::new(50.0)),
+                DirectionMode::Vertical,
+            ),
+        );
+    }
+    mocked_dom
+}
+
+#[test]
+pub fn cache_reuses_unchanged_siblings() {
+    let mut layout = Torin::<usize>::new();
+    let mut measurer = Some(TrackingMeasurer::default());
+    let mut mocked_dom = create_dom();
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    assert_eq!(
+        measurer.as_ref().unwrap().measured,
+        FxHashSet::from_iter(0..=6)
+    );
+
+    // Changing a Node without changing its size must not measure its siblings again
+    measurer.as_mut().unwrap().measured.clear();
+    mocked_dom.set_node(
+        1,
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(100.0)),
+            Size::Pixels(Length::new(100.0)),
+            DirectionMode::Horizontal,
+        ),
+    );
+    layout.invalidate(1);
+    layout.find_best_root(&mut mocked_dom);
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    assert_eq!(
+        measurer.as_ref().unwrap().measured,
+        FxHashSet::from_iter([1])
+    );
+
+    assert_eq!(
+        layout.get(3).unwrap().area,
+        Rect::new(Point2D::new(0.0, 200.0), Size2D::new(100.0, 100.0)),
+    );
+}
+
+#[test]
+pub fn cache_measures_siblings_with_new_constraints() {
+    let mut layout = Torin::<usize>::new();
+    let mut measurer = Some(TrackingMeasurer::default());
+    let mut mocked_dom = create_dom();
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    // Resizing a Node must move its next siblings
+    measurer.as_mut().unwrap().measured.clear();
+    mocked_dom.set_node(
+        1,
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(100.0)),
+            Size::Pixels(Length::new(50.0)),
+            DirectionMode::Vertical,
+        ),
+    );
+    layout.invalidate(1);
+    layout.find_best_root(&mut mocked_dom);
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    assert_eq!(
+        measurer.as_ref().unwrap().measured,
+        FxHashSet::from_iter(1..=6)
+    );
+
+    assert_eq!(
+        layout.get(3).unwrap().area,
+        Rect::new(Point2D::new(0.0, 150.0), Size2D::new(100.0, 100.0)),
+    );
+
+    assert_eq!(
+        layout.get(6).unwrap().area,
+        Rect::new(Point2D::new(0.0, 150.0), Size2D::new(50.0, 50.0)),
+    );
+}
@@ -0,0 +1,165 @@
+use torin::{prelude::*, test_utils::*};
+
+#[test]
+pub fn grid_tracks() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut mocked_dom = TestingDOM::default();
+    mocked_dom.add(
+        0,
+        None,
+        vec![1, 2, 3, 4],
+        Node::from_size_and_grid(
+            Size::Pixels(Length::new(420.0)),
+            Size::Pixels(Length::new(300.0)),
+            vec![
+                GridTrack::Pixels(Length::new(100.0)),
+                GridTrack::Fraction(Length::new(1.0)),
+                GridTrack::Fraction(Length::new(2.0)),
+            ],
+            vec![
+                GridTrack::Pixels(Length::new(50.0)),
+                GridTrack::Fraction(Length::new(1.0)),
+            ],
+            Gaps::new(10.0, 10.0, 10.0, 10.0),
+        ),
+    );
+    for i in 1..=4 {
+        mocked_dom.add(
+            i,
+            Some(0),
+            vec![],
+            Node::from_size_and_direction(
+                Size::Percentage(Length::new(100.0)),
+                Size::Percentage(Length::new(100.0)),
+                DirectionMode::Vertical,
+            ),
+        );
+    }
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    assert_eq!(
+        layout.get(1).unwrap().area.round(),
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(100.0, 50.0)),
+    );
+
+    assert_eq!(
+        layout.get(2).unwrap().area.round(),
+        Rect::new(Point2D::new(110.0, 0.0), Size2D::new(100.0, 50.0)),
+    );
+
+    assert_eq!(
+        layout.get(3).unwrap().area.round(),
+        Rect::new(Point2D::new(220.0, 0.0), Size2D::new(200.0, 50.0)),
+    );
+
+    assert_eq!(
+        layout.get(4).unwrap().area.round(),
+        Rect::new(Point2D::new(0.0, 60.0), Size2D::new(100.0, 240.0)),
+    );
+}
+
+#[test]
+pub fn grid_placement_and_auto_rows() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut mocked_dom = TestingDOM::default();
+    mocked_dom.add(
+        0,
+        None,
+        vec![1, 2, 3, 4],
+        Node::from_size_and_grid(
+            Size::Pixels(Length::new(300.0)),
+            Size::Inner,
+            vec![
+                GridTrack::Fraction(Length::new(1.0)),
+                GridTrack::Fraction(Length::new(1.0)),
+                GridTrack::Fraction(Length::new(1.0)),
+            ],
+            vec![],
+            Gaps::default(),
+        ),
+    );
+    mocked_dom.add(
+        1,
+        Some(0),
+        vec![],
+        Node::from_size_and_grid_placement(
+            Size::Percentage(Length::new(100.0)),
+            Size::Pixels(Length::new(50.0)),
+            GridPlacement::new(None, 2),
+            GridPlacement::default(),
+        ),
+    );
+    mocked_dom.add(
+        2,
+        Some(0),
+        vec![],
+        Node::from_size_and_grid_placement(
+            Size::Percentage(Length::new(100.0)),
+            Size::Pixels(Length::new(40.0)),
+            GridPlacement::new(Some(2), 1),
+            GridPlacement::new(Some(1), 1),
+        ),
+    );
+    mocked_dom.add(
+        3,
+        Some(0),
+        vec![],
+        Node::from_size_and_grid_placement(
+            Size::Percentage(Length::new(100.0)),
+            Size::Pixels(Length::new(30.0)),
+            GridPlacement::default(),
+            GridPlacement::default(),
+        ),
+    );
+    mocked_dom.add(
+        4,
+        Some(0),
+        vec![],
+        Node::from_size_and_grid_placement(
+            Size::Percentage(Length::new(100.0)),
+            Size::Fill,
+            GridPlacement::default(),
+            GridPlacement::default(),
+        ),
+    );
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    assert_eq!(
+        layout.get(0).unwrap().area,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(300.0, 90.0)),
+    );
+
+    assert_eq!(
+        layout.get(1).unwrap().area,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(200.0, 50.0)),
+    );
+
+    assert_eq!(
+        layout.get(2).unwrap().area,
+        Rect::new(Point2D::new(200.0, 50.0), Size2D::new(100.0, 40.0)),
+    );
+
+    assert_eq!(
+        layout.get(3).unwrap().area,
+        Rect::new(Point2D::new(200.0, 0.0), Size2D::new(100.0, 30.0)),
+    );
+
+    assert_eq!(
+        layout.get(4).unwrap().area,
+        Rect::new(Point2D::new(0.0, 50.0), Size2D::new(100.0, 40.0)),
+    );
+}
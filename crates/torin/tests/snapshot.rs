@@ -0,0 +1,105 @@
+use torin::{prelude::*, test_utils::*};
+
+fn create_dom(first_height: f32) -> TestingDOM {
+    let mut mocked_dom = TestingDOM::default();
+    mocked_dom.add(
+        0,
+        None,
+        vec![1, 2],
+        Node::from_size_and_padding(
+            Size::Pixels(Length::new(200.0)),
+            Size::Pixels(Length::new(200.0)),
+            Gaps::new(10.0, 10.0, 10.0, 10.0),
+        ),
+    );
+    mocked_dom.add(
+        1,
+        Some(0),
+        vec![],
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(100.0)),
+            Size::Pixels(Length::new(first_height)),
+            DirectionMode::Vertical,
+        ),
+    );
+    mocked_dom.add(
+        2,
+        Some(0),
+        vec![],
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(50.0)),
+            Size::Pixels(Length::new(50.0)),
+            DirectionMode::Vertical,
+        ),
+    );
+    mocked_dom
+}
+
+#[test]
+pub fn snapshot_format() {
+    let (mut layout, mut measurer) = test_utils();
+    let mut mocked_dom = create_dom(100.0);
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    let snapshot = layout.snapshot(0, &mut mocked_dom);
+    let text = snapshot.to_string();
+
+    assert_eq!(
+        text,
+        "0 area=0,0,200,200 inner_area=10,10,180,180 margin=0,0,0,0\n\
+         0/0 area=10,10,100,100 inner_area=10,10,100,100 margin=0,0,0,0\n\
+         0/1 area=10,110,50,50 inner_area=10,110,50,50 margin=0,0,0,0\n"
+    );
+
+    assert_eq!(LayoutSnapshot::parse(&text), Ok(snapshot));
+
+    assert_eq!(
+        LayoutSnapshot::parse("0 area=0,0,200 inner_area=0,0,0,0 margin=0,0,0,0"),
+        Err(ParseLayoutSnapshotError { line: 1 })
+    );
+}
+
+#[test]
+pub fn snapshot_diff() {
+    let (mut layout, mut measurer) = test_utils();
+    let mut mocked_dom = create_dom(100.0);
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+    let before = layout.snapshot(0, &mut mocked_dom);
+
+    assert!(before.diff(&before).is_empty());
+
+    let (mut layout, mut measurer) = test_utils();
+    let mut mocked_dom = create_dom(50.0);
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+    let after = layout.snapshot(0, &mut mocked_dom);
+
+    let diff = before.diff(&after);
+
+    assert_eq!(diff.len(), 2);
+    assert_eq!(diff[0].path(), "0/0");
+    assert!(diff[0].resized() && !diff[0].moved());
+    assert_eq!(diff[1].path(), "0/1");
+    assert!(diff[1].moved() && !diff[1].resized());
+    assert_eq!(
+        diff[1].to_string(),
+        "~ 0/1 moved from 10,110 to 10,60".to_string()
+    );
+}
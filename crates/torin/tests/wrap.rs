@@ -0,0 +1,280 @@
+use torin::{prelude::*, test_utils::*};
+
+#[test]
+pub fn wrap_horizontal() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut mocked_dom = TestingDOM::default();
+    mocked_dom.add(
+        0,
+        None,
+        vec![1, 2, 3, 4, 5],
+        Node {
+            width: Size::Pixels(Length::new(300.0)),
+            height: Size::Pixels(Length::new(300.0)),
+            direction: DirectionMode::Horizontal,
+            wrap_content: WrapContent::Wrap,
+            spacing: Gaps::new(10.0, 10.0, 10.0, 10.0),
+            ..Default::default()
+        },
+    );
+    for i in 1..=5 {
+        mocked_dom.add(
+            i,
+            Some(0),
+            vec![],
+            Node::from_size_and_direction(
+                Size::Pixels(Length::new(100.0)),
+                Size::Pixels(Length::new(50.0)),
+                DirectionMode::Vertical,
+            ),
+        );
+    }
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    assert_eq!(
+        layout.get(1).unwrap().area,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(100.0, 50.0)),
+    );
+
+    assert_eq!(
+        layout.get(2).unwrap().area,
+        Rect::new(Point2D::new(110.0, 0.0), Size2D::new(100.0, 50.0)),
+    );
+
+    assert_eq!(
+        layout.get(3).unwrap().area,
+        Rect::new(Point2D::new(0.0, 60.0), Size2D::new(100.0, 50.0)),
+    );
+
+    assert_eq!(
+        layout.get(4).unwrap().area,
+        Rect::new(Point2D::new(110.0, 60.0), Size2D::new(100.0, 50.0)),
+    );
+
+    assert_eq!(
+        layout.get(5).unwrap().area,
+        Rect::new(Point2D::new(0.0, 120.0), Size2D::new(100.0, 50.0)),
+    );
+}
+
+#[test]
+pub fn wrap_vertical() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut mocked_dom = TestingDOM::default();
+    mocked_dom.add(
+        0,
+        None,
+        vec![1, 2, 3],
+        Node {
+            width: Size::Pixels(Length::new(200.0)),
+            height: Size::Pixels(Length::new(200.0)),
+            direction: DirectionMode::Vertical,
+            wrap_content: WrapContent::Wrap,
+            ..Default::default()
+        },
+    );
+    for i in 1..=3 {
+        mocked_dom.add(
+            i,
+            Some(0),
+            vec![],
+            Node::from_size_and_direction(
+                Size::Pixels(Length::new(80.0)),
+                Size::Pixels(Length::new(80.0)),
+                DirectionMode::Vertical,
+            ),
+        );
+    }
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    assert_eq!(
+        layout.get(1).unwrap().area,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(80.0, 80.0)),
+    );
+
+    assert_eq!(
+        layout.get(2).unwrap().area,
+        Rect::new(Point2D::new(0.0, 80.0), Size2D::new(80.0, 80.0)),
+    );
+
+    assert_eq!(
+        layout.get(3).unwrap().area,
+        Rect::new(Point2D::new(80.0, 0.0), Size2D::new(80.0, 80.0)),
+    );
+}
+
+#[test]
+pub fn wrap_auto_size_and_alignments() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut mocked_dom = TestingDOM::default();
+    mocked_dom.add(
+        0,
+        None,
+        vec![1, 2, 3],
+        Node {
+            width: Size::Pixels(Length::new(250.0)),
+            height: Size::Inner,
+            direction: DirectionMode::Horizontal,
+            main_alignment: Alignment::Center,
+            cross_alignment: Alignment::Center,
+            wrap_content: WrapContent::Wrap,
+            spacing: Gaps::new(10.0, 10.0, 10.0, 10.0),
+            ..Default::default()
+        },
+    );
+    mocked_dom.add(
+        1,
+        Some(0),
+        vec![],
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(100.0)),
+            Size::Pixels(Length::new(40.0)),
+            DirectionMode::Vertical,
+        ),
+    );
+    mocked_dom.add(
+        2,
+        Some(0),
+        vec![],
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(100.0)),
+            Size::Pixels(Length::new(60.0)),
+            DirectionMode::Vertical,
+        ),
+    );
+    mocked_dom.add(
+        3,
+        Some(0),
+        vec![],
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(100.0)),
+            Size::Pixels(Length::new(40.0)),
+            DirectionMode::Vertical,
+        ),
+    );
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    assert_eq!(
+        layout.get(0).unwrap().area,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(250.0, 110.0)),
+    );
+
+    assert_eq!(
+        layout.get(1).unwrap().area,
+        Rect::new(Point2D::new(20.0, 10.0), Size2D::new(100.0, 40.0)),
+    );
+
+    assert_eq!(
+        layout.get(2).unwrap().area,
+        Rect::new(Point2D::new(130.0, 0.0), Size2D::new(100.0, 60.0)),
+    );
+
+    assert_eq!(
+        layout.get(3).unwrap().area,
+        Rect::new(Point2D::new(75.0, 70.0), Size2D::new(100.0, 40.0)),
+    );
+}
+
+#[test]
+pub fn wrap_flex() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut mocked_dom = TestingDOM::default();
+    mocked_dom.add(
+        0,
+        None,
+        vec![1, 2, 3, 4],
+        Node {
+            width: Size::Pixels(Length::new(300.0)),
+            height: Size::Pixels(Length::new(300.0)),
+            direction: DirectionMode::Horizontal,
+            wrap_content: WrapContent::Wrap,
+            spacing: Gaps::new(10.0, 10.0, 10.0, 10.0),
+            ..Default::default()
+        },
+    );
+    mocked_dom.add(
+        1,
+        Some(0),
+        vec![],
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(100.0)),
+            Size::Pixels(Length::new(50.0)),
+            DirectionMode::Vertical,
+        ),
+    );
+    mocked_dom.add(
+        2,
+        Some(0),
+        vec![],
+        Node::from_size_and_direction(
+            Size::Flex(Length::new(1.0)),
+            Size::Pixels(Length::new(50.0)),
+            DirectionMode::Vertical,
+        ),
+    );
+    mocked_dom.add(
+        3,
+        Some(0),
+        vec![],
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(200.0)),
+            Size::Pixels(Length::new(50.0)),
+            DirectionMode::Vertical,
+        ),
+    );
+    mocked_dom.add(
+        4,
+        Some(0),
+        vec![],
+        Node::from_size_and_direction(
+            Size::Flex(Length::new(1.0)),
+            Size::Pixels(Length::new(50.0)),
+            DirectionMode::Vertical,
+        ),
+    );
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    // Every flex child takes the remaining space of its own line
+    assert_eq!(
+        layout.get(2).unwrap().area,
+        Rect::new(Point2D::new(110.0, 0.0), Size2D::new(190.0, 50.0)),
+    );
+
+    assert_eq!(
+        layout.get(3).unwrap().area,
+        Rect::new(Point2D::new(0.0, 60.0), Size2D::new(200.0, 50.0)),
+    );
+
+    assert_eq!(
+        layout.get(4).unwrap().area,
+        Rect::new(Point2D::new(210.0, 60.0), Size2D::new(90.0, 50.0)),
+    );
+}
@@ -0,0 +1,40 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+use freya::prelude::*;
+
+fn main() {
+    launch_with_props(app, "Flex", (400.0, 350.0));
+}
+
+fn app() -> Element {
+    rsx!(
+        rect {
+            height: "100%",
+            width: "100%",
+            direction: "horizontal",
+            rect {
+                height: "100%",
+                width: "flex(1)",
+                background: "rgb(0, 119, 182)",
+            }
+            rect {
+                height: "100%",
+                width: "flex(2)",
+                background: "rgb(20, 150, 220)",
+            }
+            rect {
+                height: "100%",
+                width: "flex(1)",
+                background: "rgb(0, 119, 182)",
+            }
+            rect {
+                height: "100%",
+                width: "100",
+                background: "rgb(20, 150, 220)",
+            }
+        }
+    )
+}
@@ -0,0 +1,46 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+use freya::prelude::*;
+
+fn main() {
+    launch_with_props(app, "Grid", (400.0, 350.0));
+}
+
+fn app() -> Element {
+    rsx!(
+        rect {
+            height: "100%",
+            width: "100%",
+            grid_columns: "100 1fr 2fr",
+            grid_rows: "50 1fr 1fr",
+            spacing: "10",
+            padding: "10",
+            rect {
+                grid_column: "1 / span 3",
+                height: "100%",
+                width: "100%",
+                corner_radius: "8",
+                background: "rgb(0, 119, 182)",
+            }
+            rect {
+                grid_row: "2 / span 2",
+                height: "100%",
+                width: "100%",
+                corner_radius: "8",
+                background: "rgb(20, 150, 220)",
+            }
+            for i in 0..4 {
+                rect {
+                    key: "{i}",
+                    height: "100%",
+                    width: "100%",
+                    corner_radius: "8",
+                    background: "rgb(70, 180, 240)",
+                }
+            }
+        }
+    )
+}
@@ -0,0 +1,33 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+use freya::prelude::*;
+
+fn main() {
+    launch_with_props(app, "Wrap content", (400.0, 350.0));
+}
+
+fn app() -> Element {
+    rsx!(
+        rect {
+            height: "100%",
+            width: "100%",
+            direction: "horizontal",
+            wrap_content: "wrap",
+            main_align: "center",
+            spacing: "10",
+            padding: "10",
+            for i in 0..25 {
+                rect {
+                    key: "{i}",
+                    height: "40",
+                    width: "{40 + (i % 4) * 20}",
+                    corner_radius: "8",
+                    background: "rgb(0, 119, 182)",
+                }
+            }
+        }
+    )
+}